@@ -0,0 +1,47 @@
+//! Benchmarks of parsing sizes.
+//!
+//! Run using `cargo bench --bench parse`.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use human_size::Size;
+
+#[path = "../tests/reference/mod.rs"]
+mod reference;
+
+use reference::reference_parse;
+
+/// Sizes as found in log files.
+const INPUTS: &[&str] = &[
+    "0 B",
+    "512 B",
+    "4096B",
+    "12 kB",
+    "100 MiB",
+    "1.5 GiB",
+    "0.512 TB",
+    " 123456789 B ",
+];
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    group.bench_function("from_str", |b| {
+        b.iter(|| {
+            for input in INPUTS {
+                let _ = black_box(black_box(input).parse::<Size>());
+            }
+        })
+    });
+    group.bench_function("original", |b| {
+        b.iter(|| {
+            for input in INPUTS {
+                let _ = black_box(reference_parse(black_box(input)));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
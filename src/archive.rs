@@ -0,0 +1,43 @@
+//! Support for zero-copy deserialisation using [`rkyv`].
+//!
+//! [`rkyv`]: https://docs.rs/rkyv
+
+use rkyv::Archive;
+
+use super::ArchivedSpecificSize;
+
+impl<M: Archive> ArchivedSpecificSize<M> {
+    /// Returns the size in current the multiple, see [`SpecificSize::value`].
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # extern crate rkyv;
+    /// # fn main() {
+    /// use human_size::{ArchivedSpecificSize, Size};
+    /// use human_size::Any;
+    /// use rkyv::rancor::Error;
+    ///
+    /// let size: Size = "10 MiB".parse().unwrap();
+    /// let bytes = rkyv::to_bytes::<Error>(&size).unwrap();
+    ///
+    /// let archived = rkyv::access::<ArchivedSpecificSize<Any>, Error>(&bytes).unwrap();
+    /// assert_eq!(archived.value(), 10.0);
+    /// assert_eq!(*archived.multiple(), Any::Mebibyte);
+    ///
+    /// let size2: Size = rkyv::deserialize::<_, Error>(archived).unwrap();
+    /// assert_eq!(size, size2);
+    /// # }
+    /// ```
+    ///
+    /// [`SpecificSize::value`]: struct.SpecificSize.html#method.value
+    pub fn value(&self) -> f64 {
+        self.value.to_native()
+    }
+
+    /// Returns the archived multiple, see [`SpecificSize::multiple`].
+    ///
+    /// [`SpecificSize::multiple`]: struct.SpecificSize.html#method.multiple
+    pub fn multiple(&self) -> &M::Archived {
+        &self.multiple
+    }
+}
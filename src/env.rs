@@ -0,0 +1,196 @@
+//! Reading sizes from environment variables.
+//!
+//! ```
+//! # extern crate human_size;
+//! # fn main() {
+//! use human_size::env::{from_env, from_env_or};
+//! use human_size::{Mebibyte, Size, SpecificSize};
+//!
+//! # unsafe { std::env::set_var("CACHE_SIZE", "512MiB"); }
+//! // CACHE_SIZE=512MiB
+//! let size: Option<Size> = from_env("CACHE_SIZE").unwrap();
+//! assert_eq!(size, Some("512 MiB".parse().unwrap()));
+//!
+//! // Using a default if the variable is not set.
+//! let default = SpecificSize::new(64, Mebibyte).unwrap();
+//! let size = from_env_or("BUFFER_SIZE", default).unwrap();
+//! assert_eq!(size, default);
+//!
+//! # unsafe { std::env::set_var("MAX_BODY_SIZE", "10"); }
+//! // MAX_BODY_SIZE=10
+//! let err = from_env::<Mebibyte>("MAX_BODY_SIZE").unwrap_err();
+//! assert_eq!(err.to_string(), "invalid size in environment variable `MAX_BODY_SIZE`: no multiple");
+//! # }
+//! ```
+//!
+//! Sizes can be parsed from command line flags using the [`FromStr`]
+//! implementation of [`SpecificSize`].
+//!
+//! [`FromStr`]: https://doc.rust-lang.org/nightly/core/str/trait.FromStr.html
+//! [`SpecificSize`]: ../struct.SpecificSize.html
+
+use std::error::Error;
+use std::ops::RangeBounds;
+use std::{env, fmt};
+
+use super::{Multiple, ParsingError, Size, SpecificSize};
+
+/// Read the size from environment variable `name`.
+///
+/// Returns `Ok(None)` if the variable is not set.
+pub fn from_env<M: Multiple>(name: &str) -> Result<Option<SpecificSize<M>>, EnvSizeError> {
+    from_env_with(name, |name| env::var(name))
+}
+
+/// Same as [`from_env`], but using `lookup` to get the value of the variable,
+/// rather than the environment of the process.
+///
+/// This can be used to read sizes from other sources of variables, e.g. a
+/// parsed `.env` file, or in tests, as changing the environment is not
+/// thread-safe.
+///
+/// ```
+/// # extern crate human_size;
+/// # fn main() {
+/// use std::collections::HashMap;
+/// use std::env::VarError;
+///
+/// use human_size::env::from_env_with;
+/// use human_size::{Any, Size, SpecificSize};
+///
+/// let vars = HashMap::from([("CACHE_SIZE", "512MiB")]);
+/// let lookup = |name: &str| vars.get(name).map(|value| value.to_string()).ok_or(VarError::NotPresent);
+///
+/// let size: Option<Size> = from_env_with("CACHE_SIZE", lookup).unwrap();
+/// assert_eq!(size, Some(SpecificSize::new(512, Any::Mebibyte).unwrap()));
+/// let size: Option<Size> = from_env_with("BUFFER_SIZE", lookup).unwrap();
+/// assert_eq!(size, None);
+/// # }
+/// ```
+///
+/// [`from_env`]: fn.from_env.html
+pub fn from_env_with<M, F>(name: &str, lookup: F) -> Result<Option<SpecificSize<M>>, EnvSizeError>
+where
+    M: Multiple,
+    F: FnOnce(&str) -> Result<String, env::VarError>,
+{
+    let value = match lookup(name) {
+        Ok(value) => value,
+        Err(env::VarError::NotPresent) => return Ok(None),
+        Err(env::VarError::NotUnicode(_)) => {
+            return Err(EnvSizeError::NotUnicode {
+                name: name.to_owned(),
+            });
+        }
+    };
+    value
+        .parse()
+        .map(Some)
+        .map_err(|err| EnvSizeError::Parsing {
+            name: name.to_owned(),
+            err,
+        })
+}
+
+/// Read the size from environment variable `name`, returning `default` if
+/// the variable is not set.
+pub fn from_env_or<M: Multiple>(
+    name: &str,
+    default: SpecificSize<M>,
+) -> Result<SpecificSize<M>, EnvSizeError> {
+    from_env(name).map(|size| size.unwrap_or(default))
+}
+
+/// Read the size from environment variable `name`, returning an error if the
+/// size is not within `range`.
+///
+/// Returns `Ok(None)` if the variable is not set.
+///
+/// ```
+/// # extern crate human_size;
+/// # fn main() {
+/// use human_size::env::from_env_in;
+/// use human_size::{Kibibyte, SpecificSize};
+///
+/// # unsafe { std::env::set_var("CACHE_SIZE", "2 GiB"); }
+/// // CACHE_SIZE=2 GiB
+/// let min = SpecificSize::new(4, Kibibyte).unwrap();
+/// let max = SpecificSize::new(1024 * 1024, Kibibyte).unwrap();
+/// let err = from_env_in::<Kibibyte, _>("CACHE_SIZE", min..=max).unwrap_err();
+/// assert_eq!(err.to_string(), "size in environment variable `CACHE_SIZE` is out of range: 2097152 KiB");
+/// # }
+/// ```
+pub fn from_env_in<M, R>(name: &str, range: R) -> Result<Option<SpecificSize<M>>, EnvSizeError>
+where
+    M: Multiple + Copy,
+    R: RangeBounds<SpecificSize<M>>,
+{
+    match from_env(name)? {
+        Some(size) if !range.contains(&size) => Err(EnvSizeError::OutOfRange {
+            name: name.to_owned(),
+            size: size.into(),
+        }),
+        size => Ok(size),
+    }
+}
+
+/// The error returned when reading a size from an environment variable.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EnvSizeError {
+    /// The value of the variable is not valid unicode.
+    NotUnicode {
+        /// Name of the variable.
+        name: String,
+    },
+    /// The value of the variable can't be parsed as a size.
+    Parsing {
+        /// Name of the variable.
+        name: String,
+        /// The parsing error.
+        err: ParsingError,
+    },
+    /// The size is not within the allowed range.
+    OutOfRange {
+        /// Name of the variable.
+        name: String,
+        /// The size in the variable.
+        size: Size,
+    },
+}
+
+impl EnvSizeError {
+    /// Returns the name of the environment variable.
+    pub fn name(&self) -> &str {
+        match self {
+            EnvSizeError::NotUnicode { name }
+            | EnvSizeError::Parsing { name, .. }
+            | EnvSizeError::OutOfRange { name, .. } => name,
+        }
+    }
+}
+
+impl fmt::Display for EnvSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(&match self {
+            EnvSizeError::NotUnicode { name } => {
+                format!("environment variable `{}` is not valid unicode", name)
+            }
+            EnvSizeError::Parsing { name, err } => {
+                format!("invalid size in environment variable `{}`: {}", name, err)
+            }
+            EnvSizeError::OutOfRange { name, size } => format!(
+                "size in environment variable `{}` is out of range: {}",
+                name, size
+            ),
+        })
+    }
+}
+
+impl Error for EnvSizeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            EnvSizeError::Parsing { err, .. } => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,417 @@
+//! Sizes of files and directories.
+//!
+//! [`MetadataExt`] and [`PathExt`] return the size of a single file, while
+//! [`Walker`] determines the size of a directory, including all files in it,
+//! similar to `du(1)`.
+//!
+//! ```
+//! # extern crate human_size;
+//! # fn main() -> std::io::Result<()> {
+//! use std::path::Path;
+//!
+//! use human_size::fs::{PathExt, Walker};
+//!
+//! let size = Path::new("Cargo.toml").file_size()?;
+//! assert!(size.value() > 0.0);
+//!
+//! let usage = Walker::new().walk("src")?;
+//! assert!(usage.total() >= size);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`MetadataExt`]: trait.MetadataExt.html
+//! [`PathExt`]: trait.PathExt.html
+//! [`Walker`]: struct.Walker.html
+
+use std::collections::HashSet;
+use std::error::Error;
+use std::fs::{self, Metadata};
+use std::path::{Path, PathBuf};
+use std::{fmt, io};
+
+use super::{Byte, SpecificSize, byte_size};
+
+/// Extension trait for [`Metadata`] to get the size of a file.
+///
+/// [`Metadata`]: https://doc.rust-lang.org/std/fs/struct.Metadata.html
+pub trait MetadataExt {
+    /// Returns the apparent size of the file, i.e. `Metadata::len`.
+    fn file_size(&self) -> SpecificSize<Byte>;
+
+    /// Returns the size allocated on disk for the file, which can be smaller
+    /// (e.g. for sparse files) or larger (e.g. for partially used blocks)
+    /// than the apparent size.
+    ///
+    /// On Unix this uses the number of allocated 512 byte blocks, on other
+    /// platforms this is the same as the apparent size.
+    fn allocated_size(&self) -> SpecificSize<Byte>;
+}
+
+impl MetadataExt for Metadata {
+    fn file_size(&self) -> SpecificSize<Byte> {
+        byte_size(self.len())
+    }
+
+    #[cfg(unix)]
+    fn allocated_size(&self) -> SpecificSize<Byte> {
+        use std::os::unix::fs::MetadataExt;
+        // Unlike `st_blksize` the size of blocks in `st_blocks` is always 512
+        // bytes.
+        byte_size(self.blocks().saturating_mul(512))
+    }
+
+    #[cfg(not(unix))]
+    fn allocated_size(&self) -> SpecificSize<Byte> {
+        self.file_size()
+    }
+}
+
+/// Extension trait for [`Path`] to get the size of a file.
+///
+/// [`Path`]: https://doc.rust-lang.org/std/path/struct.Path.html
+pub trait PathExt {
+    /// Returns the apparent size of the file, following symbolic links.
+    ///
+    /// For the size of a directory including its contents see [`Walker`].
+    ///
+    /// [`Walker`]: struct.Walker.html
+    fn file_size(&self) -> io::Result<SpecificSize<Byte>>;
+}
+
+impl PathExt for Path {
+    fn file_size(&self) -> io::Result<SpecificSize<Byte>> {
+        fs::metadata(self).map(|metadata| metadata.file_size())
+    }
+}
+
+/// Which size of files to count.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SizeKind {
+    /// The apparent size, see [`MetadataExt::file_size`].
+    ///
+    /// [`MetadataExt::file_size`]: trait.MetadataExt.html#tymethod.file_size
+    Apparent,
+    /// The size allocated on disk, see [`MetadataExt::allocated_size`].
+    ///
+    /// [`MetadataExt::allocated_size`]: trait.MetadataExt.html#tymethod.allocated_size
+    Allocated,
+}
+
+/// How to handle symbolic links.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Symlinks {
+    /// Ignore symbolic links.
+    Skip,
+    /// Count the size of the symbolic link itself, but don't follow it.
+    Count,
+    /// Follow symbolic links, counting the size of the file or directory it
+    /// points to. Directories are only walked once, to prevent loops.
+    Follow,
+}
+
+/// Recursive directory size walker.
+///
+/// By default this counts the allocated size of files, doesn't follow
+/// symbolic links and counts hard links to the same file only once, matching
+/// the defaults of `du(1)`.
+///
+/// Hard links and directories are recognised by their device and inode
+/// numbers, which are only available on Unix.
+///
+/// Unlike `du(1)` the walk stops at the first error by default, e.g. when a
+/// directory can't be read, see [`Walker::collect_errors`] to continue
+/// instead.
+///
+/// [`Walker::collect_errors`]: struct.Walker.html#method.collect_errors
+#[derive(Clone, Debug)]
+pub struct Walker {
+    size_kind: SizeKind,
+    symlinks: Symlinks,
+    dedupe_hardlinks: bool,
+    collect_errors: bool,
+}
+
+impl Walker {
+    /// Create a new walker with the default options.
+    pub fn new() -> Walker {
+        Walker {
+            size_kind: SizeKind::Allocated,
+            symlinks: Symlinks::Count,
+            dedupe_hardlinks: true,
+            collect_errors: false,
+        }
+    }
+
+    /// Set which size of files to count, defaults to
+    /// [`SizeKind::Allocated`].
+    ///
+    /// [`SizeKind::Allocated`]: enum.SizeKind.html#variant.Allocated
+    pub fn size_kind(mut self, size_kind: SizeKind) -> Walker {
+        self.size_kind = size_kind;
+        self
+    }
+
+    /// Set how to handle symbolic links, defaults to [`Symlinks::Count`].
+    ///
+    /// [`Symlinks::Count`]: enum.Symlinks.html#variant.Count
+    pub fn symlinks(mut self, symlinks: Symlinks) -> Walker {
+        self.symlinks = symlinks;
+        self
+    }
+
+    /// Whether or not to count hard links to the same file only once,
+    /// defaults to `true`.
+    pub fn dedupe_hardlinks(mut self, dedupe: bool) -> Walker {
+        self.dedupe_hardlinks = dedupe;
+        self
+    }
+
+    /// Whether or not to continue walking after an error, defaults to
+    /// `false`.
+    ///
+    /// If `true` entries that can't be read are skipped, and the errors are
+    /// returned in [`Usage::errors`], similar to `du(1)`. Note that the size
+    /// of directories that can't be read (completely) only includes the
+    /// entries that could be read.
+    ///
+    /// [`Usage::errors`]: struct.Usage.html#method.errors
+    pub fn collect_errors(mut self, collect: bool) -> Walker {
+        self.collect_errors = collect;
+        self
+    }
+
+    /// Walk `path`, returning the total size and the size of all entries.
+    ///
+    /// If `path` is a symbolic link it is always followed. Reading the
+    /// metadata of `path` itself always returns an error on failure, other
+    /// errors depend on [`Walker::collect_errors`].
+    ///
+    /// [`Walker::collect_errors`]: struct.Walker.html#method.collect_errors
+    pub fn walk<P: AsRef<Path>>(&self, path: P) -> io::Result<Usage> {
+        let path = path.as_ref();
+        let metadata = fs::metadata(path)?;
+        let mut walk = Walk {
+            walker: self,
+            seen: HashSet::new(),
+            entries: Vec::new(),
+            errors: Vec::new(),
+        };
+        let total = walk.entry(path.to_owned(), metadata)?.unwrap_or(0);
+        Ok(Usage {
+            total: byte_size(total),
+            entries: walk.entries,
+            errors: walk.errors,
+        })
+    }
+}
+
+impl Default for Walker {
+    fn default() -> Walker {
+        Walker::new()
+    }
+}
+
+/// Result of [`Walker::walk`].
+///
+/// [`Walker::walk`]: struct.Walker.html#method.walk
+#[derive(Debug)]
+pub struct Usage {
+    total: SpecificSize<Byte>,
+    entries: Vec<Entry>,
+    errors: Vec<WalkError>,
+}
+
+impl Usage {
+    /// Returns the total size.
+    pub fn total(&self) -> SpecificSize<Byte> {
+        self.total
+    }
+
+    /// Returns all counted entries, including the walked path itself.
+    ///
+    /// Entries within a directory are returned before the directory itself,
+    /// the size of a directory includes the size of all its entries.
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Returns all errors encountered while walking, only used if
+    /// [`Walker::collect_errors`] is enabled.
+    ///
+    /// [`Walker::collect_errors`]: struct.Walker.html#method.collect_errors
+    pub fn errors(&self) -> &[WalkError] {
+        &self.errors
+    }
+}
+
+/// File or directory in [`Usage`].
+///
+/// [`Usage`]: struct.Usage.html
+#[derive(Clone, Debug)]
+pub struct Entry {
+    path: PathBuf,
+    size: SpecificSize<Byte>,
+    is_dir: bool,
+}
+
+impl Entry {
+    /// Returns the path of the entry.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the size of the entry, for directories this includes the size
+    /// of all its entries.
+    pub fn size(&self) -> SpecificSize<Byte> {
+        self.size
+    }
+
+    /// Returns `true` if the entry is a directory.
+    pub fn is_dir(&self) -> bool {
+        self.is_dir
+    }
+}
+
+/// Error encountered while walking, see [`Usage::errors`].
+///
+/// [`Usage::errors`]: struct.Usage.html#method.errors
+#[derive(Debug)]
+pub struct WalkError {
+    path: PathBuf,
+    error: io::Error,
+}
+
+impl WalkError {
+    /// Returns the path of the entry that caused the error.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the underlying I/O error.
+    pub fn io_error(&self) -> &io::Error {
+        &self.error
+    }
+}
+
+impl fmt::Display for WalkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.error)
+    }
+}
+
+impl Error for WalkError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// State of a single walk.
+struct Walk<'w> {
+    walker: &'w Walker,
+    /// Device and inode numbers of visited hard links and directories.
+    seen: HashSet<(u64, u64)>,
+    entries: Vec<Entry>,
+    errors: Vec<WalkError>,
+}
+
+impl Walk<'_> {
+    /// Count the entry at `path`, returns `None` if it shouldn't be counted.
+    fn entry(&mut self, path: PathBuf, metadata: Metadata) -> io::Result<Option<u64>> {
+        let metadata = if metadata.file_type().is_symlink() {
+            match self.walker.symlinks {
+                Symlinks::Skip => return Ok(None),
+                Symlinks::Count => metadata,
+                // Dangling symbolic links are counted as is.
+                Symlinks::Follow => match fs::metadata(&path) {
+                    Ok(metadata) => metadata,
+                    Err(ref err) if err.kind() == io::ErrorKind::NotFound => metadata,
+                    Err(err) => {
+                        self.error(&path, err)?;
+                        return Ok(None);
+                    }
+                },
+            }
+        } else {
+            metadata
+        };
+
+        if !self.first_visit(&metadata) {
+            return Ok(None);
+        }
+
+        let mut size = match self.walker.size_kind {
+            SizeKind::Apparent => metadata.len(),
+            SizeKind::Allocated => metadata.allocated_size().value() as u64,
+        };
+        if metadata.is_dir() {
+            match fs::read_dir(&path) {
+                Ok(entries) => {
+                    for entry in entries {
+                        let entry = match entry {
+                            Ok(entry) => entry,
+                            Err(err) => {
+                                self.error(&path, err)?;
+                                break;
+                            }
+                        };
+                        let entry_path = entry.path();
+                        // `DirEntry::metadata` doesn't follow symbolic links.
+                        let entry_metadata = match entry.metadata() {
+                            Ok(metadata) => metadata,
+                            Err(err) => {
+                                self.error(&entry_path, err)?;
+                                continue;
+                            }
+                        };
+                        if let Some(entry_size) = self.entry(entry_path, entry_metadata)? {
+                            size = size.saturating_add(entry_size);
+                        }
+                    }
+                }
+                Err(err) => self.error(&path, err)?,
+            }
+        }
+
+        self.entries.push(Entry {
+            path,
+            size: byte_size(size),
+            is_dir: metadata.is_dir(),
+        });
+        Ok(Some(size))
+    }
+
+    /// Collects `err` for the entry at `path`, or returns it if errors are
+    /// not collected.
+    fn error(&mut self, path: &Path, err: io::Error) -> io::Result<()> {
+        if self.walker.collect_errors {
+            self.errors.push(WalkError {
+                path: path.to_owned(),
+                error: err,
+            });
+            Ok(())
+        } else {
+            Err(err)
+        }
+    }
+
+    /// Returns `false` if the file is a hard link that was already counted,
+    /// or a directory that was already walked.
+    #[cfg(unix)]
+    fn first_visit(&mut self, metadata: &Metadata) -> bool {
+        use std::os::unix::fs::MetadataExt;
+        let check = if metadata.is_dir() {
+            // Only following symbolic links can cause a directory to be
+            // visited twice.
+            self.walker.symlinks == Symlinks::Follow
+        } else {
+            self.walker.dedupe_hardlinks && metadata.nlink() > 1
+        };
+        !check || self.seen.insert((metadata.dev(), metadata.ino()))
+    }
+
+    #[cfg(not(unix))]
+    fn first_visit(&mut self, _: &Metadata) -> bool {
+        true
+    }
+}
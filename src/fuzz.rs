@@ -0,0 +1,34 @@
+//! Implementations of [`arbitrary`]'s `Arbitrary` trait.
+//!
+//! Just like the [`proptest`] strategies all generated sizes have a valid, not
+//! negative value.
+//!
+//! [`arbitrary`]: https://docs.rs/arbitrary
+//! [`proptest`]: ../strategy/index.html
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::{Any, Multiple, SpecificSize, from_value, is_valid_value};
+
+impl<'a, M: Multiple> Arbitrary<'a> for SpecificSize<M> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let multiple = Any::arbitrary(u)?;
+        let value = f64::arbitrary(u)?.abs();
+        let value = if is_valid_value(value) { value } else { 0.0 };
+        Ok(from_value(value, multiple))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(Any::size_hint(depth), f64::size_hint(depth))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Any {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(Any::all()).copied()
+    }
+
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
@@ -0,0 +1,603 @@
+//! I/O adapters that count or limit the number of bytes read or written.
+//!
+//! [`CountingReader`] and [`CountingWriter`] wrap a reader or writer and keep
+//! track of the [`Progress`], optionally calling a callback at fixed
+//! intervals. [`LimitedReader`] and [`LimitedWriter`] return an error once a
+//! size limit is exceeded.
+//!
+//! ```
+//! # extern crate human_size;
+//! # fn main() -> std::io::Result<()> {
+//! use std::io;
+//!
+//! use human_size::io::CountingReader;
+//! use human_size::{Mebibyte, SpecificSize};
+//!
+//! let input = vec![0; 3 * 1024 * 1024];
+//! let mut reports = Vec::new();
+//! let mut reader = CountingReader::new(&input[..])
+//!     .total(SpecificSize::new(3, Mebibyte).unwrap())
+//!     .on_progress(SpecificSize::new(1, Mebibyte).unwrap(), |progress| {
+//!         reports.push(format!("copied {:.1}", progress));
+//!     });
+//! io::copy(&mut reader, &mut io::sink())?;
+//!
+//! assert_eq!(reports, [
+//!     "copied 1.0 MiB of 3.0 MiB (33%)",
+//!     "copied 2.0 MiB of 3.0 MiB (67%)",
+//!     "copied 3.0 MiB of 3.0 MiB (100%)",
+//! ]);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`CountingReader`]: struct.CountingReader.html
+//! [`CountingWriter`]: struct.CountingWriter.html
+//! [`Progress`]: struct.Progress.html
+//! [`LimitedReader`]: struct.LimitedReader.html
+//! [`LimitedWriter`]: struct.LimitedWriter.html
+
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::time::{Duration, Instant};
+
+use super::{Byte, Multiple, Size, SpecificSize, UnitSystem, byte_size};
+
+/// Progress of reading or writing.
+#[derive(Copy, Clone, Debug)]
+pub struct Progress {
+    bytes: u64,
+    total: Option<u64>,
+    start: Instant,
+}
+
+impl Progress {
+    fn new() -> Progress {
+        Progress {
+            bytes: 0,
+            total: None,
+            start: Instant::now(),
+        }
+    }
+
+    /// Returns the number of bytes read or written so far.
+    pub fn bytes(&self) -> SpecificSize<Byte> {
+        byte_size(self.bytes)
+    }
+
+    /// Returns the total number of bytes, if known.
+    pub fn total(&self) -> Option<SpecificSize<Byte>> {
+        self.total.map(byte_size)
+    }
+
+    /// Returns the percentage done, between 0 and 100, if the total is known.
+    pub fn percent(&self) -> Option<f64> {
+        self.total.map(|total| {
+            if total == 0 {
+                100.0
+            } else {
+                (self.bytes as f64 / total as f64 * 100.0).min(100.0)
+            }
+        })
+    }
+
+    /// Returns the time elapsed since the adapter was created.
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Returns the average number of bytes per second.
+    pub fn throughput(&self) -> SpecificSize<Byte> {
+        let secs = self.elapsed().as_secs_f64();
+        if secs == 0.0 {
+            byte_size(0)
+        } else {
+            SpecificSize {
+                value: self.bytes as f64 / secs,
+                multiple: Byte,
+            }
+        }
+    }
+
+    /// Returns the estimated time remaining, based on the average throughput.
+    ///
+    /// Returns `None` if the total is not known or nothing has been read or
+    /// written yet.
+    pub fn eta(&self) -> Option<Duration> {
+        let total = self.total?;
+        if self.bytes == 0 {
+            return None;
+        }
+        let remaining = total.saturating_sub(self.bytes) as f64;
+        let secs = self.elapsed().as_secs_f64() * remaining / self.bytes as f64;
+        Duration::try_from_secs_f64(secs).ok()
+    }
+}
+
+/// Formats the progress as "1.2 GiB of 4 GiB (30%)", or "1.2 GiB" if the
+/// total is not known. The precision, if any, is used for the sizes.
+impl fmt::Display for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision();
+        let size = |bytes| {
+            let size = byte_size(bytes).to_system(UnitSystem::Binary);
+            match precision {
+                Some(precision) => format!("{:.*}", precision, size),
+                None => size.to_string(),
+            }
+        };
+        match (self.total, self.percent()) {
+            (Some(total), Some(percent)) => write!(
+                f,
+                "{} of {} ({:.0}%)",
+                size(self.bytes),
+                size(total),
+                percent
+            ),
+            _ => f.write_str(&size(self.bytes)),
+        }
+    }
+}
+
+/// Progress with an optional callback.
+struct Counter<F> {
+    progress: Progress,
+    /// Interval in bytes, and the number of bytes at which to call the
+    /// callback next.
+    interval: u64,
+    next: u64,
+    callback: Option<F>,
+}
+
+impl<F> Counter<F> {
+    fn new() -> Counter<F> {
+        Counter {
+            progress: Progress::new(),
+            interval: 0,
+            next: 0,
+            callback: None,
+        }
+    }
+
+    fn total<M: Multiple>(mut self, total: SpecificSize<M>) -> Counter<F> {
+        // Ignores rounding errors, e.g. "32.3 kB" is 32300 bytes, not 32299.
+        self.progress.total = Some(total.to_bytes_saturating());
+        self
+    }
+
+    fn on_progress<M, G>(self, interval: SpecificSize<M>, callback: G) -> Counter<G>
+    where
+        M: Multiple,
+    {
+        let interval = interval.to_bytes_saturating();
+        Counter {
+            progress: self.progress,
+            interval,
+            next: self.progress.bytes.saturating_add(interval.max(1)),
+            callback: Some(callback),
+        }
+    }
+
+    fn count(&mut self, n: usize)
+    where
+        F: FnMut(&Progress),
+    {
+        self.progress.bytes = self.progress.bytes.saturating_add(n as u64);
+        if let Some(callback) = &mut self.callback
+            && self.progress.bytes >= self.next
+        {
+            callback(&self.progress);
+            // Call the callback once, even if multiple intervals were passed.
+            let interval = self.interval.max(1);
+            self.next = (self.progress.bytes / interval)
+                .saturating_add(1)
+                .saturating_mul(interval);
+        }
+    }
+}
+
+/// Reader that counts the number of bytes read.
+///
+/// See the [module documentation] for an example.
+///
+/// [module documentation]: index.html
+pub struct CountingReader<R, F = fn(&Progress)> {
+    inner: R,
+    counter: Counter<F>,
+}
+
+impl<R> CountingReader<R> {
+    /// Create a new `CountingReader`.
+    pub fn new(inner: R) -> CountingReader<R> {
+        CountingReader {
+            inner,
+            counter: Counter::new(),
+        }
+    }
+}
+
+impl<R, F> CountingReader<R, F> {
+    /// Set the total number of bytes that will be read, used in [`Progress`].
+    ///
+    /// Fractional bytes are rounded down, negative sizes are treated as zero
+    /// bytes, see [`SpecificSize::to_bytes_saturating`].
+    ///
+    /// [`Progress`]: struct.Progress.html
+    /// [`SpecificSize::to_bytes_saturating`]: ../struct.SpecificSize.html#method.to_bytes_saturating
+    pub fn total<M: Multiple>(self, total: SpecificSize<M>) -> CountingReader<R, F> {
+        CountingReader {
+            inner: self.inner,
+            counter: self.counter.total(total),
+        }
+    }
+
+    /// Call `callback` each time another `interval` of bytes is read.
+    pub fn on_progress<M, G>(self, interval: SpecificSize<M>, callback: G) -> CountingReader<R, G>
+    where
+        M: Multiple,
+        G: FnMut(&Progress),
+    {
+        CountingReader {
+            inner: self.inner,
+            counter: self.counter.on_progress(interval, callback),
+        }
+    }
+
+    /// Returns the current progress.
+    pub fn progress(&self) -> &Progress {
+        &self.counter.progress
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read, F: FnMut(&Progress)> Read for CountingReader<R, F> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.counter.count(n);
+        Ok(n)
+    }
+}
+
+impl<R: fmt::Debug, F> fmt::Debug for CountingReader<R, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CountingReader")
+            .field("inner", &self.inner)
+            .field("progress", &self.counter.progress)
+            .finish()
+    }
+}
+
+/// Writer that counts the number of bytes written.
+///
+/// See [`CountingReader`] for an example.
+///
+/// [`CountingReader`]: struct.CountingReader.html
+pub struct CountingWriter<W, F = fn(&Progress)> {
+    inner: W,
+    counter: Counter<F>,
+}
+
+impl<W> CountingWriter<W> {
+    /// Create a new `CountingWriter`.
+    pub fn new(inner: W) -> CountingWriter<W> {
+        CountingWriter {
+            inner,
+            counter: Counter::new(),
+        }
+    }
+}
+
+impl<W, F> CountingWriter<W, F> {
+    /// Set the total number of bytes that will be written, used in
+    /// [`Progress`].
+    ///
+    /// Fractional bytes are rounded down, negative sizes are treated as zero
+    /// bytes, see [`SpecificSize::to_bytes_saturating`].
+    ///
+    /// [`Progress`]: struct.Progress.html
+    /// [`SpecificSize::to_bytes_saturating`]: ../struct.SpecificSize.html#method.to_bytes_saturating
+    pub fn total<M: Multiple>(self, total: SpecificSize<M>) -> CountingWriter<W, F> {
+        CountingWriter {
+            inner: self.inner,
+            counter: self.counter.total(total),
+        }
+    }
+
+    /// Call `callback` each time another `interval` of bytes is written.
+    pub fn on_progress<M, G>(self, interval: SpecificSize<M>, callback: G) -> CountingWriter<W, G>
+    where
+        M: Multiple,
+        G: FnMut(&Progress),
+    {
+        CountingWriter {
+            inner: self.inner,
+            counter: self.counter.on_progress(interval, callback),
+        }
+    }
+
+    /// Returns the current progress.
+    pub fn progress(&self) -> &Progress {
+        &self.counter.progress
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write, F: FnMut(&Progress)> Write for CountingWriter<W, F> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.counter.count(n);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<W: fmt::Debug, F> fmt::Debug for CountingWriter<W, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CountingWriter")
+            .field("inner", &self.inner)
+            .field("progress", &self.counter.progress)
+            .finish()
+    }
+}
+
+/// Reader that returns an error if more bytes than a limit are read.
+///
+/// Unlike [`Read::take`], which silently stops reading at the limit, this
+/// returns an error with kind `FileTooLarge` and a [`LimitExceededError`]
+/// once the underlying reader returns more bytes than the limit. To detect
+/// this a single byte past the limit is read from the underlying reader.
+///
+/// ```
+/// # extern crate human_size;
+/// # fn main() {
+/// use std::io::{self, Read};
+///
+/// use human_size::io::{LimitExceededError, LimitedReader};
+/// use human_size::Size;
+///
+/// let limit: Size = "1 KiB".parse().unwrap();
+/// let body = vec![0; 2000];
+/// let mut reader = LimitedReader::new(&body[..], limit);
+/// let err = reader.read_to_end(&mut Vec::new()).unwrap_err();
+/// assert_eq!(err.kind(), io::ErrorKind::FileTooLarge);
+/// let err = LimitExceededError::from_io(&err).unwrap();
+/// assert_eq!(err.limit(), limit);
+/// assert_eq!(err.to_string(), "size limit of 1 KiB exceeded, attempted 1025 B");
+/// # }
+/// ```
+///
+/// [`Read::take`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.take
+/// [`LimitExceededError`]: struct.LimitExceededError.html
+#[derive(Debug)]
+pub struct LimitedReader<R> {
+    inner: R,
+    limit: Size,
+    limit_bytes: u64,
+    bytes: u64,
+}
+
+impl<R> LimitedReader<R> {
+    /// Create a new `LimitedReader`.
+    ///
+    /// The limit is rounded down to a whole number of bytes, ignoring
+    /// rounding errors, e.g. a limit of "32.3 kB" allows 32300 bytes to be
+    /// read. A negative limit is treated as zero bytes. See
+    /// [`SpecificSize::to_bytes_saturating`].
+    ///
+    /// [`SpecificSize::to_bytes_saturating`]: ../struct.SpecificSize.html#method.to_bytes_saturating
+    pub fn new<M: Multiple>(inner: R, limit: SpecificSize<M>) -> LimitedReader<R> {
+        let limit = limit.into();
+        LimitedReader {
+            inner,
+            limit,
+            limit_bytes: limit.to_bytes_saturating(),
+            bytes: 0,
+        }
+    }
+
+    /// Returns the limit.
+    pub fn limit(&self) -> Size {
+        self.limit
+    }
+
+    /// Returns the number of bytes read so far.
+    pub fn bytes_read(&self) -> SpecificSize<Byte> {
+        byte_size(self.bytes)
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for LimitedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        // Read at most a single byte past the limit.
+        let remaining = self.limit_bytes.saturating_sub(self.bytes);
+        let max = usize::try_from(remaining.saturating_add(1)).unwrap_or(usize::MAX);
+        let max = buf.len().min(max);
+        let n = self.inner.read(&mut buf[..max])?;
+        let bytes = self.bytes.saturating_add(n as u64);
+        if bytes > self.limit_bytes {
+            return Err(LimitExceededError::new(self.limit, bytes).into());
+        }
+        self.bytes = bytes;
+        Ok(n)
+    }
+}
+
+/// Writer that returns an error if more bytes than a limit are written.
+///
+/// A write that would exceed the limit is rejected as a whole, nothing is
+/// written to the underlying writer. The returned error has kind
+/// `FileTooLarge` and contains a [`LimitExceededError`].
+///
+/// [`LimitExceededError`]: struct.LimitExceededError.html
+#[derive(Debug)]
+pub struct LimitedWriter<W> {
+    inner: W,
+    limit: Size,
+    limit_bytes: u64,
+    bytes: u64,
+}
+
+impl<W> LimitedWriter<W> {
+    /// Create a new `LimitedWriter`.
+    ///
+    /// The limit is rounded down to a whole number of bytes, ignoring
+    /// rounding errors, e.g. a limit of "32.3 kB" allows 32300 bytes to be
+    /// written. A negative limit is treated as zero bytes. See
+    /// [`SpecificSize::to_bytes_saturating`].
+    ///
+    /// [`SpecificSize::to_bytes_saturating`]: ../struct.SpecificSize.html#method.to_bytes_saturating
+    pub fn new<M: Multiple>(inner: W, limit: SpecificSize<M>) -> LimitedWriter<W> {
+        let limit = limit.into();
+        LimitedWriter {
+            inner,
+            limit,
+            limit_bytes: limit.to_bytes_saturating(),
+            bytes: 0,
+        }
+    }
+
+    /// Returns the limit.
+    pub fn limit(&self) -> Size {
+        self.limit
+    }
+
+    /// Returns the number of bytes written so far.
+    pub fn bytes_written(&self) -> SpecificSize<Byte> {
+        byte_size(self.bytes)
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for LimitedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let bytes = self.bytes.saturating_add(buf.len() as u64);
+        if bytes > self.limit_bytes {
+            return Err(LimitExceededError::new(self.limit, bytes).into());
+        }
+        let n = self.inner.write(buf)?;
+        self.bytes += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// The error returned by [`LimitedReader`] and [`LimitedWriter`] when the
+/// limit is exceeded, wrapped in an [`io::Error`].
+///
+/// [`LimitedReader`]: struct.LimitedReader.html
+/// [`LimitedWriter`]: struct.LimitedWriter.html
+/// [`io::Error`]: https://doc.rust-lang.org/std/io/struct.Error.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct LimitExceededError {
+    limit: Size,
+    attempted: SpecificSize<Byte>,
+}
+
+impl LimitExceededError {
+    fn new(limit: Size, attempted: u64) -> LimitExceededError {
+        LimitExceededError {
+            limit,
+            attempted: byte_size(attempted),
+        }
+    }
+
+    /// Returns the `LimitExceededError` within `err`, if any.
+    pub fn from_io(err: &io::Error) -> Option<&LimitExceededError> {
+        err.get_ref()?.downcast_ref()
+    }
+
+    /// Returns the limit that was exceeded.
+    pub fn limit(&self) -> Size {
+        self.limit
+    }
+
+    /// Returns the total number of bytes that was attempted to be read or
+    /// written, including the bytes that were already read or written.
+    pub fn attempted(&self) -> SpecificSize<Byte> {
+        self.attempted
+    }
+}
+
+impl fmt::Display for LimitExceededError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(&format!(
+            "size limit of {} exceeded, attempted {}",
+            self.limit, self.attempted
+        ))
+    }
+}
+
+impl Error for LimitExceededError {}
+
+impl From<LimitExceededError> for io::Error {
+    fn from(err: LimitExceededError) -> io::Error {
+        io::Error::new(io::ErrorKind::FileTooLarge, err)
+    }
+}
@@ -12,6 +12,7 @@
     variant_size_differences
 )]
 #![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //! The `human_size` crate represents sizes for humans.
 //!
@@ -48,15 +49,64 @@
 //! Internally `f64` is used to represent the size, so when comparing sizes with
 //! different multiples be wary of rounding errors related to usage of floating
 //! point numbers.
+//!
+//! # Features
+//!
+//! The crate can be used without the standard library by disabling the
+//! default `std` feature. [`SpecificSize`], [`Any`], all multiples, parsing
+//! and formatting are always available. The [`registry`] module requires the
+//! `alloc` feature, the [`env`], [`fs`], [`io`], [`numfmt`] and [`sort`]
+//! modules require the `std` feature.
+//!
+//! [`Any`]: multiples/enum.Any.html
+//! [`registry`]: registry/index.html
+//! [`env`]: env/index.html
+//! [`fs`]: fs/index.html
+//! [`io`]: io/index.html
+//! [`numfmt`]: numfmt/index.html
+//! [`sort`]: sort/index.html
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
-use std::cmp::Ordering;
-use std::error::Error;
-use std::fmt;
-use std::str::FromStr;
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Sub};
+use core::str::{self, FromStr};
 
+#[cfg(feature = "rkyv")]
+mod archive;
+#[cfg(feature = "std")]
+pub mod env;
+#[cfg(feature = "std")]
+pub mod fs;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+#[cfg(feature = "std")]
+pub mod io;
+// The resolvers generated by rkyv for the multiples don't implement `Debug`.
+#[cfg_attr(feature = "rkyv", allow(missing_debug_implementations))]
 pub mod multiples;
+#[cfg(feature = "num-traits")]
+mod num;
+#[cfg(feature = "std")]
+pub mod numfmt;
+#[cfg(feature = "alloc")]
+pub mod registry;
+#[cfg(feature = "std")]
+pub mod sort;
+#[cfg(feature = "rusqlite")]
+pub mod sqlite;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+mod string;
+#[cfg(feature = "valuable")]
+mod value;
 
 pub use multiples::*;
+pub use string::SizeString;
 
 /// Size with a generic [`Multiple`].
 ///
@@ -112,17 +162,29 @@ pub type Size = SpecificSize<Any>;
 /// When comparing sizes with one another it is to possible compare different
 /// multiples, see the first example above. However due to a lack of precision
 /// in floating point numbers equality ignores a difference less then
-/// `0.00000001`, after applying the multiple. See the `PartialEq`
-/// implementation (via \[src\] to the right) for details.
+/// `0.00000001` bytes, or a relative difference up to `0.000000000001`. See
+/// the `PartialEq` implementation (via \[src\] to the right) for details, and
+/// [`SpecificSize::approx_eq`] to compare sizes using a different tolerance.
+/// The `PartialOrd` implementation is consistent with this, sizes that are
+/// equal are never less or greater than each other.
 ///
 /// The same is true for converting to and from multiples, here again the lack
 /// of precision of floating points can be a cause of bugs.
 ///
+/// Because of this `SpecificSize` doesn't implement `Ord` or `Hash`, use
+/// [`OrdSize`] to use sizes as keys in a `BTreeMap` or `HashMap`.
+///
 /// [`FromStr`]: https://doc.rust-lang.org/nightly/core/str/trait.FromStr.html
+/// [`OrdSize`]: struct.OrdSize.html
+/// [`SpecificSize::approx_eq`]: struct.SpecificSize.html#method.approx_eq
 /// [`Multiple`]: trait.Multiple.html
 /// [`Kilobyte`]: multiples/struct.Kilobyte.html
 /// [`Any`]: multiples/enum.Any.html
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct SpecificSize<M = Any> {
     value: f64,
     multiple: M,
@@ -160,6 +222,32 @@ impl<M: Multiple> SpecificSize<M> {
         }
     }
 
+    /// Same as [`SpecificSize::new`], but usable in constants.
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use human_size::{Any, Size, SpecificSize};
+    ///
+    /// const MAX_BODY: Size = SpecificSize::new_const(10.0, Any::Mebibyte);
+    /// assert_eq!(MAX_BODY.to_string(), "10 MiB");
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `value` is not valid, which fails compilation when used
+    /// in a constant.
+    ///
+    /// [`SpecificSize::new`]: struct.SpecificSize.html#method.new
+    pub const fn new_const(value: f64, multiple: M) -> SpecificSize<M>
+    where
+        M: Copy,
+    {
+        assert!(is_valid_value(value), "invalid size value");
+        SpecificSize { value, multiple }
+    }
+
     /// Conversion between sizes with different multiples.
     ///
     /// This allows a size with one multiple to be converted into a size with
@@ -193,6 +281,126 @@ impl<M: Multiple> SpecificSize<M> {
         M2::from_any(value, any)
     }
 
+    /// Conversion into a multiple only known at runtime, e.g. a variant of
+    /// [`Any`] or a [`Custom`] multiple.
+    ///
+    /// Unlike [`SpecificSize::into`] this uses the provided `multiple`, rather
+    /// than only its type.
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use human_size::{SpecificSize, Any, Custom, Kilobyte};
+    ///
+    /// let size = SpecificSize::new(1, Kilobyte).unwrap();
+    /// let size2 = size.to_multiple(Any::Byte);
+    ///
+    /// assert_eq!(size, size2);
+    /// assert_eq!(size2.to_string(), "1000 B");
+    ///
+    /// let sector = Custom::new("sector", "sectors", 500.0);
+    /// assert_eq!(size.to_multiple(sector).to_string(), "2 sectors");
+    /// # }
+    /// ```
+    ///
+    /// [`Any`]: multiples/enum.Any.html
+    /// [`Custom`]: multiples/struct.Custom.html
+    /// [`SpecificSize::into`]: struct.SpecificSize.html#method.into
+    pub fn to_multiple<M2>(self, multiple: M2) -> SpecificSize<M2>
+    where
+        M2: Multiple + Copy,
+    {
+        let value = into_multiple(self, multiple);
+        SpecificSize { value, multiple }
+    }
+
+    /// Convert the size into the largest multiple of `system` for which the
+    /// value is at least one, e.g. 1500 bytes become 1.5 kB in the decimal
+    /// system. Sizes smaller than a kilobyte (or kibibyte) are converted into
+    /// bytes.
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use human_size::{SpecificSize, Byte, UnitSystem};
+    ///
+    /// let size = SpecificSize::new(1_572_864, Byte).unwrap();
+    ///
+    /// assert_eq!(size.to_system(UnitSystem::Decimal).to_string(), "1.572864 MB");
+    /// assert_eq!(size.to_system(UnitSystem::Binary).to_string(), "1.5 MiB");
+    /// # }
+    /// ```
+    pub fn to_system(self, system: UnitSystem) -> Size {
+        let (value, multiple) = M::into_any(self);
+        let size = SpecificSize { value, multiple };
+        let bytes = (value * multiple.multiple_of_bytes()).abs();
+        let multiple = system
+            .multiples()
+            .iter()
+            .rev()
+            .find(|multiple| bytes >= multiple.multiple_of_bytes())
+            .copied()
+            .unwrap_or(Any::Byte);
+        size.to_multiple(multiple)
+    }
+
+    /// Convert the size into the best fitting multiple of the decimal (SI)
+    /// system, see [`SpecificSize::to_system`].
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use human_size::Size;
+    ///
+    /// let size: Size = "1.5 MiB".parse().unwrap();
+    /// assert_eq!(size.to_decimal().to_string(), "1.572864 MB");
+    /// # }
+    /// ```
+    ///
+    /// [`SpecificSize::to_system`]: struct.SpecificSize.html#method.to_system
+    pub fn to_decimal(self) -> Size {
+        self.to_system(UnitSystem::Decimal)
+    }
+
+    /// Convert the size into the best fitting multiple of the binary (IEC)
+    /// system, see [`SpecificSize::to_system`].
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use human_size::Size;
+    ///
+    /// let size: Size = "1572864 B".parse().unwrap();
+    /// assert_eq!(size.to_binary().to_string(), "1.5 MiB");
+    /// # }
+    /// ```
+    ///
+    /// [`SpecificSize::to_system`]: struct.SpecificSize.html#method.to_system
+    pub fn to_binary(self) -> Size {
+        self.to_system(UnitSystem::Binary)
+    }
+
+    /// Convert the size into the best fitting multiple of the JEDEC system,
+    /// formatted using the JEDEC symbols, see [`Jedec`].
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use human_size::Size;
+    ///
+    /// let size: Size = "1572864 B".parse().unwrap();
+    /// assert_eq!(size.to_jedec().to_string(), "1.5 MB");
+    /// assert_eq!(size.to_jedec().size().to_string(), "1.5 MiB");
+    /// # }
+    /// ```
+    ///
+    /// [`Jedec`]: struct.Jedec.html
+    pub fn to_jedec(self) -> Jedec {
+        Jedec {
+            size: self.to_system(UnitSystem::Jedec),
+        }
+    }
+
     /// Returns the size in current the multiple.
     ///
     /// ```
@@ -248,46 +456,841 @@ impl<M: Multiple> SpecificSize<M> {
     /// # }
     /// ```
     pub fn to_bytes(self) -> u64 {
+        self.bytes() as u64
+    }
+
+    /// Returns the size as bytes, or an error if the size is negative, too
+    /// large or not a whole number of bytes. This is the same as using the
+    /// [`TryFrom`] implementation.
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use human_size::{SpecificSize, Byte, Yobibyte, TryFromSizeError};
+    ///
+    /// let size = SpecificSize::new(1.5, Byte).unwrap();
+    /// assert_eq!(size.try_to_bytes(), Err(TryFromSizeError::Fractional));
+    ///
+    /// let size = SpecificSize::new(1, Yobibyte).unwrap();
+    /// assert_eq!(size.try_to_bytes(), Err(TryFromSizeError::Overflow));
+    /// # }
+    /// ```
+    ///
+    /// [`TryFrom`]: enum.TryFromSizeError.html
+    pub fn try_to_bytes(self) -> Result<u64, TryFromSizeError> {
+        u64::try_from(self)
+    }
+
+    /// Returns the size as bytes, rounding fractional bytes using `rounding`.
+    /// Returns an error if the rounded size is negative or too large.
+    ///
+    /// See [`Rounding`] for how rounding errors and negative sizes are
+    /// handled.
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use human_size::{SpecificSize, Byte, Rounding};
+    ///
+    /// let size = SpecificSize::new(1.5, Byte).unwrap();
+    /// assert_eq!(size.to_bytes_rounded(Rounding::Down), Ok(1));
+    /// assert_eq!(size.to_bytes_rounded(Rounding::Up), Ok(2));
+    /// assert_eq!(size.to_bytes_rounded(Rounding::Nearest), Ok(2));
+    /// # }
+    /// ```
+    ///
+    /// [`Rounding`]: enum.Rounding.html
+    pub fn to_bytes_rounded(self, rounding: Rounding) -> Result<u64, TryFromSizeError> {
+        let bytes = self.whole_bytes_of(Some(rounding))?;
+        u64::try_from(bytes).map_err(|_| TryFromSizeError::Overflow)
+    }
+
+    /// Returns the size as bytes in a `u128`, for sizes that don't fit in a
+    /// `u64`. Returns an error if the size is negative, too large or not a
+    /// whole number of bytes.
+    ///
+    /// For sizes with a whole value the result is exact, even if the number
+    /// of bytes can't be represented as `f64`.
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use human_size::{SpecificSize, Yobibyte, Yottabyte};
+    ///
+    /// let size = SpecificSize::new(1, Yobibyte).unwrap();
+    /// assert_eq!(size.to_bytes_u128(), Ok(1 << 80));
+    ///
+    /// let size = SpecificSize::new(3, Yottabyte).unwrap();
+    /// assert_eq!(size.to_bytes_u128(), Ok(3_000_000_000_000_000_000_000_000));
+    /// # }
+    /// ```
+    pub fn to_bytes_u128(self) -> Result<u128, TryFromSizeError> {
+        u128::try_from(self)
+    }
+
+    /// Returns the size as bytes, rounding fractional bytes down and
+    /// saturating at the bounds of `u64`, i.e. negative sizes return zero and
+    /// sizes that are too large return `u64::MAX`.
+    ///
+    /// Unlike [`SpecificSize::to_bytes`] this ignores rounding errors, e.g.
+    /// "32.3 kB" returns 32300 rather than 32299.
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use human_size::{SpecificSize, Kilobyte, Yobibyte};
+    ///
+    /// let size = SpecificSize::new(32.3, Kilobyte).unwrap();
+    /// assert_eq!(size.to_bytes(), 32299);
+    /// assert_eq!(size.to_bytes_saturating(), 32300);
+    ///
+    /// let size = SpecificSize::new(1, Yobibyte).unwrap();
+    /// assert_eq!(size.to_bytes_saturating(), u64::MAX);
+    /// # }
+    /// ```
+    ///
+    /// [`SpecificSize::to_bytes`]: struct.SpecificSize.html#method.to_bytes
+    pub fn to_bytes_saturating(self) -> u64 {
+        match self.to_bytes_rounded(Rounding::Down) {
+            Ok(bytes) => bytes,
+            Err(TryFromSizeError::Negative) => 0,
+            Err(_) => u64::MAX,
+        }
+    }
+
+    /// Add `rhs` to this size, returning `None` if the result is not a valid
+    /// value (see [`SpecificSize::new`]). The result has the same multiple as
+    /// `self`.
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use human_size::{SpecificSize, Byte, Kilobyte};
+    ///
+    /// let size1 = SpecificSize::new(1, Kilobyte).unwrap();
+    /// let size2 = SpecificSize::new(500, Byte).unwrap();
+    ///
+    /// assert_eq!(size1.checked_add(size2).unwrap().to_string(), "1.5 kB");
+    ///
+    /// let max = SpecificSize::new(f64::MAX, Kilobyte).unwrap();
+    /// assert_eq!(max.checked_add(max), None);
+    /// # }
+    /// ```
+    ///
+    /// [`SpecificSize::new`]: struct.SpecificSize.html#method.new
+    pub fn checked_add<RM>(self, rhs: SpecificSize<RM>) -> Option<SpecificSize<M>>
+    where
+        M: Copy,
+        RM: Multiple,
+    {
+        let rhs = into_multiple(rhs, self.multiple);
+        let value = self.value + rhs;
+        is_valid_value(value).then_some(SpecificSize {
+            value,
+            multiple: self.multiple,
+        })
+    }
+
+    /// Subtract `rhs` from this size, returning `None` if the result is not a
+    /// valid value (see [`SpecificSize::new`]). The result has the same
+    /// multiple as `self`.
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use human_size::{SpecificSize, Byte, Kilobyte};
+    ///
+    /// let size1 = SpecificSize::new(1, Kilobyte).unwrap();
+    /// let size2 = SpecificSize::new(500, Byte).unwrap();
+    ///
+    /// assert_eq!(size1.checked_sub(size2).unwrap().to_string(), "0.5 kB");
+    /// # }
+    /// ```
+    ///
+    /// [`SpecificSize::new`]: struct.SpecificSize.html#method.new
+    pub fn checked_sub<RM>(self, rhs: SpecificSize<RM>) -> Option<SpecificSize<M>>
+    where
+        M: Copy,
+        RM: Multiple,
+    {
+        let rhs = into_multiple(rhs, self.multiple);
+        let value = self.value - rhs;
+        is_valid_value(value).then_some(SpecificSize {
+            value,
+            multiple: self.multiple,
+        })
+    }
+
+    /// Returns `true` if `self` and `other` are equal within `tolerance`,
+    /// comparing the sizes in bytes.
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use human_size::{SpecificSize, Size, Tolerance};
+    ///
+    /// let size1: Size = "1 YB".parse().unwrap();
+    /// let size2: Size = "1.000001 YB".parse().unwrap();
+    ///
+    /// assert!(size1 != size2);
+    /// assert!(size1.approx_eq(size2, Tolerance::Relative(0.00001)));
+    /// assert!(!size1.approx_eq(size2, Tolerance::Absolute(1_000_000.0)));
+    /// # }
+    /// ```
+    pub fn approx_eq<RM>(self, other: SpecificSize<RM>, tolerance: Tolerance) -> bool
+    where
+        RM: Multiple,
+    {
+        let (left, right) = (self.bytes(), other.bytes());
+        if left == right {
+            return true;
+        }
+        let diff = (left - right).abs();
+        match tolerance {
+            Tolerance::Absolute(max) => diff <= max,
+            Tolerance::Relative(max) => diff <= max * left.abs().max(right.abs()),
+            Tolerance::Ulps(max) => ulps(left, right) <= max,
+        }
+    }
+
+    /// Returns the smaller of `self` and `other`, returning `self` if they're
+    /// equal.
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use human_size::{SpecificSize, Size};
+    ///
+    /// let size1: Size = "1 MB".parse().unwrap();
+    /// let size2: Size = "1 MiB".parse().unwrap();
+    ///
+    /// assert_eq!(size1.min(size2).to_string(), "1 MB");
+    /// assert_eq!(size1.max(size2).to_string(), "1 MiB");
+    /// # }
+    /// ```
+    pub fn min(self, other: SpecificSize<M>) -> SpecificSize<M>
+    where
+        M: Copy,
+    {
+        if other < self { other } else { self }
+    }
+
+    /// Returns the larger of `self` and `other`, returning `other` if they're
+    /// equal.
+    pub fn max(self, other: SpecificSize<M>) -> SpecificSize<M>
+    where
+        M: Copy,
+    {
+        if other < self { self } else { other }
+    }
+
+    /// Restrict the size to the range `min..=max`.
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use human_size::{SpecificSize, Size};
+    ///
+    /// let min: Size = "4 KiB".parse().unwrap();
+    /// let max: Size = "1 GiB".parse().unwrap();
+    ///
+    /// let size: Size = "100 B".parse().unwrap();
+    /// assert_eq!(size.clamp(min, max), min);
+    /// let size: Size = "100 MB".parse().unwrap();
+    /// assert_eq!(size.clamp(min, max), size);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is larger than `max`.
+    pub fn clamp(self, min: SpecificSize<M>, max: SpecificSize<M>) -> SpecificSize<M>
+    where
+        M: Copy,
+    {
+        assert!(min <= max, "min size larger than max size");
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Returns the size in bytes, without any rounding.
+    pub(crate) fn bytes(self) -> f64 {
         let (value, any) = M::into_any(self);
-        Byte::from_any(value, any).value as u64
+        Byte::from_any(value, any).value
+    }
+
+    /// Returns the size as a whole number of bytes, see [`whole_bytes`].
+    ///
+    /// Sizes with a whole value are converted exactly, e.g. "1 YB" is 10^24
+    /// bytes, which as `f64` is 999999999999999983222784.
+    pub(crate) fn whole_bytes_of(
+        self,
+        rounding: Option<Rounding>,
+    ) -> Result<u128, TryFromSizeError> {
+        let (value, multiple) = M::into_any(self);
+        // `u128::MAX as f64` rounds up to 2^128, which doesn't fit.
+        let whole = value as u128;
+        if value >= 0.0 && value < u128::MAX as f64 && whole as f64 == value {
+            whole
+                .checked_mul(multiple.multiple_of_bytes_u128())
+                .ok_or(TryFromSizeError::Overflow)
+        } else {
+            whole_bytes(Byte::from_any(value, multiple).value, rounding)
+        }
+    }
+}
+
+/// Adding sizes with (possibly) different multiples, the result has the
+/// multiple of the left side.
+///
+/// # Panics
+///
+/// This panics if the result is not a valid value, e.g. if it overflows to
+/// infinity. Use [`SpecificSize::checked_add`] to handle that case.
+///
+/// [`SpecificSize::checked_add`]: struct.SpecificSize.html#method.checked_add
+impl<LM, RM> Add<SpecificSize<RM>> for SpecificSize<LM>
+where
+    LM: Multiple + Copy,
+    RM: Multiple,
+{
+    type Output = SpecificSize<LM>;
+
+    fn add(self, rhs: SpecificSize<RM>) -> Self::Output {
+        self.checked_add(rhs).expect("overflow when adding sizes")
+    }
+}
+
+/// Subtracting sizes with (possibly) different multiples, the result has the
+/// multiple of the left side.
+///
+/// # Panics
+///
+/// This panics if the result is not a valid value, e.g. if it overflows to
+/// infinity. Use [`SpecificSize::checked_sub`] to handle that case.
+///
+/// [`SpecificSize::checked_sub`]: struct.SpecificSize.html#method.checked_sub
+impl<LM, RM> Sub<SpecificSize<RM>> for SpecificSize<LM>
+where
+    LM: Multiple + Copy,
+    RM: Multiple,
+{
+    type Output = SpecificSize<LM>;
+
+    fn sub(self, rhs: SpecificSize<RM>) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting sizes")
+    }
+}
+
+/// Implements `TryFrom<SpecificSize<M>>` for the integer types, see
+/// [`TryFromSizeError`].
+///
+/// [`TryFromSizeError`]: enum.TryFromSizeError.html
+macro_rules! try_from_size {
+    ($( $ty: ty ),*) => {
+        $(
+        impl<M: Multiple> TryFrom<SpecificSize<M>> for $ty {
+            type Error = TryFromSizeError;
+
+            fn try_from(size: SpecificSize<M>) -> Result<$ty, Self::Error> {
+                let bytes = size.whole_bytes_of(None)?;
+                <$ty>::try_from(bytes).map_err(|_| TryFromSizeError::Overflow)
+            }
+        }
+        )*
+    };
+}
+
+try_from_size!(u64, usize, u128);
+
+/// Convert `bytes` into a whole number of bytes, rounding fractional bytes
+/// using `rounding`, or returning an error if it's `None`.
+fn whole_bytes(bytes: f64, rounding: Option<Rounding>) -> Result<u128, TryFromSizeError> {
+    // `u128::MAX as f64` is rounded up to 2^128, so we use that (which can be
+    // represented exactly) as limit.
+    let limit = ((u128::MAX / 2 + 1) as f64) * 2.0;
+    // `whole` is `None` for -1 byte, as negative sizes between -1 and 0 bytes
+    // can still be rounded to zero.
+    let (whole, fraction) = if bytes >= limit {
+        return Err(TryFromSizeError::Overflow);
+    } else if bytes >= 0.0 {
+        let whole = bytes as u128;
+        (Some(whole), bytes - whole as f64)
+    } else if bytes > -1.0 {
+        (None, bytes + 1.0)
+    } else {
+        return Err(TryFromSizeError::Negative);
+    };
+
+    // Rounding errors are ignored, see `CMP_MARGIN`.
+    let round_up = if fraction < CMP_MARGIN {
+        false
+    } else if 1.0 - fraction < CMP_MARGIN {
+        true
+    } else {
+        match rounding {
+            None if whole.is_none() => return Err(TryFromSizeError::Negative),
+            None => return Err(TryFromSizeError::Fractional),
+            Some(Rounding::Down) => false,
+            Some(Rounding::Up) => true,
+            Some(Rounding::Nearest) => fraction >= 0.5,
+        }
+    };
+    match whole {
+        Some(whole) => whole
+            .checked_add(u128::from(round_up))
+            .ok_or(TryFromSizeError::Overflow),
+        None if round_up => Ok(0),
+        None => Err(TryFromSizeError::Negative),
+    }
+}
+
+/// How to round fractional bytes, see [`SpecificSize::to_bytes_rounded`].
+///
+/// Fractions within 0.00000001 bytes of a whole number of bytes are
+/// considered rounding errors, e.g. 32.3 kB is 32299.999999999996 bytes as
+/// `f64`, and are always rounded to that whole number, regardless of the
+/// rounding mode. For example 0.999999995 bytes is rounded to 1 byte, even
+/// when rounding down.
+///
+/// Negative sizes that are rounded to zero, e.g. -0.3 bytes when rounding up,
+/// result in zero bytes. All other negative sizes result in an error.
+///
+/// [`SpecificSize::to_bytes_rounded`]: struct.SpecificSize.html#method.to_bytes_rounded
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Rounding {
+    /// Round down, e.g. 1.5 bytes becomes 1 byte.
+    Down,
+    /// Round up, e.g. 1.2 bytes becomes 2 bytes.
+    Up,
+    /// Round to the nearest number of bytes, rounding half way cases up,
+    /// e.g. 1.5 bytes becomes 2 bytes.
+    Nearest,
+}
+
+/// Create a `SpecificSize` with `value` in multiple `M`, *without* converting
+/// the value. For `Any` the provided `multiple` is used, for all other multiples
+/// it's ignored.
+#[cfg(any(feature = "enable-serde", feature = "proptest", feature = "arbitrary"))]
+pub(crate) fn from_value<M: Multiple>(value: f64, multiple: Any) -> SpecificSize<M> {
+    let mut size = M::from_any(0.0, multiple);
+    size.value = value;
+    size
+}
+
+/// Create a size from a number of bytes.
+#[cfg(feature = "std")]
+pub(crate) fn byte_size(bytes: u64) -> SpecificSize<Byte> {
+    SpecificSize {
+        value: bytes as f64,
+        multiple: Byte,
     }
 }
 
 /// Check if the provided `value` is valid.
-fn is_valid_value(value: f64) -> bool {
-    use std::num::FpCategory::*;
+pub(crate) const fn is_valid_value(value: f64) -> bool {
+    use core::num::FpCategory::*;
     matches!(value.classify(), Normal | Zero)
 }
 
+impl<M: Multiple> SpecificSize<M> {
+    /// Parse a size from bytes, following the same rules as the [`FromStr`]
+    /// implementation.
+    ///
+    /// Input that is not valid UTF-8 is never a valid size.
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use human_size::{Size, SpecificSize, Any, ParsingError};
+    ///
+    /// let size = Size::parse_bytes(b"10 MiB").unwrap();
+    /// assert_eq!(size, SpecificSize::new(10, Any::Mebibyte).unwrap());
+    ///
+    /// let res = Size::parse_bytes(b"10 \xFF");
+    /// assert_eq!(res, Err(ParsingError::InvalidMultiple));
+    /// # }
+    /// ```
+    ///
+    /// [`FromStr`]: https://doc.rust-lang.org/nightly/core/str/trait.FromStr.html
+    pub fn parse_bytes(input: &[u8]) -> Result<SpecificSize<M>, ParsingError> {
+        match str::from_utf8(input) {
+            Ok(input) => input.parse(),
+            // Invalid UTF-8 is never whitespace, part of the value or part
+            // of the multiple, so we can handle it like ASCII.
+            Err(_) => parse_ascii(input),
+        }
+    }
+
+    /// Parse a size at the start of `input`, returning the size and the
+    /// remainder of the input after it.
+    ///
+    /// Unlike the [`FromStr`] implementation this doesn't fail on trailing
+    /// input. Leading whitespace and whitespace between the value and
+    /// multiple is skipped, the multiple ends at the first character that is
+    /// not an ASCII letter. Only ASCII digits are accepted in the value.
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use human_size::{Size, SpecificSize, Any, ParsingError};
+    ///
+    /// let (size, rest) = Size::parse_prefix("10MiB;next=20 kB").unwrap();
+    /// assert_eq!(size, SpecificSize::new(10, Any::Mebibyte).unwrap());
+    /// assert_eq!(rest, ";next=20 kB");
+    ///
+    /// let (size, rest) = Size::parse_prefix("10 B extra").unwrap();
+    /// assert_eq!(size, SpecificSize::new(10, Any::Byte).unwrap());
+    /// assert_eq!(rest, " extra");
+    ///
+    /// // The multiple must not be followed by other letters.
+    /// let res = Size::parse_prefix("10 kBps");
+    /// assert_eq!(res, Err(ParsingError::InvalidMultiple));
+    /// # }
+    /// ```
+    ///
+    /// [`FromStr`]: https://doc.rust-lang.org/nightly/core/str/trait.FromStr.html
+    pub fn parse_prefix(input: &str) -> Result<(SpecificSize<M>, &str), ParsingError> {
+        let input = input.trim_start();
+        if input.is_empty() {
+            return Err(ParsingError::EmptyInput);
+        }
+
+        let (value_len, fast_value) = scan_value(input.as_bytes());
+        if value_len == 0 {
+            return Err(ParsingError::MissingValue);
+        }
+
+        let (value, rest) = input.split_at(value_len);
+        let rest = rest.trim_start();
+        let multiple_len = rest
+            .bytes()
+            .position(|b| !b.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        if multiple_len == 0 {
+            return Err(ParsingError::MissingMultiple);
+        }
+
+        let value = parse_value(value.as_bytes(), fast_value)?;
+        let (multiple, rest) = rest.split_at(multiple_len);
+        let multiple =
+            Any::from_symbol(multiple.as_bytes()).ok_or(ParsingError::InvalidMultiple)?;
+        Ok((M::from_any(value, multiple), rest))
+    }
+}
+
 impl<M: Multiple> FromStr for SpecificSize<M> {
     type Err = ParsingError;
 
     fn from_str(input: &str) -> Result<SpecificSize<M>, Self::Err> {
-        let input = input.trim();
+        if input.is_ascii() {
+            parse_ascii(input.as_bytes())
+        } else {
+            parse_unicode(input)
+        }
+    }
+}
+
+/// Fast path of the `FromStr` implementation of `SpecificSize` for ASCII
+/// input.
+fn parse_ascii<M: Multiple>(input: &[u8]) -> Result<SpecificSize<M>, ParsingError> {
+    let (value, multiple) = split_ascii(input)?;
+    let multiple = Any::from_symbol(multiple).ok_or(ParsingError::InvalidMultiple)?;
+    Ok(M::from_any(value, multiple))
+}
+
+/// Split a size into its value, which is parsed, and the trimmed multiple.
+///
+/// Used by the `FromStr` implementation of `SpecificSize` and
+/// `UnitRegistry::parse`, which only differ in the multiples they accept.
+#[cfg(feature = "alloc")]
+pub(crate) fn split_size(input: &str) -> Result<(f64, &str), ParsingError> {
+    if input.is_ascii() {
+        let (value, multiple) = split_ascii(input.as_bytes())?;
+        // Part of the ASCII input, so always valid UTF-8.
+        let multiple = str::from_utf8(multiple).map_err(|_| ParsingError::InvalidMultiple)?;
+        Ok((value, multiple))
+    } else {
+        split_unicode(input)
+    }
+}
+
+/// Same as [`split_size`] for ASCII input.
+fn split_ascii(input: &[u8]) -> Result<(f64, &[u8]), ParsingError> {
+    let input = trim_ascii_whitespace(input);
+    if input.is_empty() {
+        return Err(ParsingError::EmptyInput);
+    }
+
+    let (multiple_index, fast_value) = scan_value(input);
+    if multiple_index == input.len() {
+        return Err(ParsingError::MissingMultiple);
+    } else if multiple_index == 0 {
+        return Err(ParsingError::MissingValue);
+    }
+
+    let (value, multiple) = input.split_at(multiple_index);
+    let value = parse_value(value, fast_value)?;
+    Ok((value, trim_ascii_whitespace(multiple)))
+}
+
+/// Scan the value (ASCII digits and dots) at the start of `input`, returning
+/// its length in bytes.
+///
+/// This parses the value as integer (ignoring the decimal point) at the same
+/// time, which avoids the slower float parsing for most sizes. If that is
+/// possible the value is returned as well.
+fn scan_value(input: &[u8]) -> (usize, Option<f64>) {
+    let mut integer: Option<u64> = Some(0);
+    let mut digits = 0;
+    let mut decimals = None;
+    let mut length = 0;
+    while let Some(&b) = input.get(length) {
+        match b {
+            b'0'..=b'9' => {
+                integer = integer
+                    .and_then(|n| n.checked_mul(10))
+                    .and_then(|n| n.checked_add(u64::from(b - b'0')));
+                digits += 1;
+                if let Some(decimals) = decimals.as_mut() {
+                    *decimals += 1;
+                }
+            }
+            b'.' if decimals.is_none() => decimals = Some(0),
+            // Let the float parsing return the error.
+            b'.' => integer = None,
+            _ => break,
+        }
+        length += 1;
+    }
+
+    let value = match (integer, decimals.unwrap_or(0)) {
+        // Integers up to 2^53 and powers of ten up to 10^22 can be
+        // represented exactly, so dividing them gives the same correctly
+        // rounded result as parsing it as float.
+        (Some(integer), decimals) if integer <= 1 << 53 && decimals <= 22 && digits != 0 => {
+            Some(integer as f64 / POWERS_OF_TEN[decimals])
+        }
+        _ => None,
+    };
+    (length, value)
+}
+
+/// Parse the `value` returned by [`scan_value`], using the `fast_value` if
+/// any.
+fn parse_value(value: &[u8], fast_value: Option<f64>) -> Result<f64, ParsingError> {
+    let value = match fast_value {
+        Some(value) => value,
+        None => str::from_utf8(value)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .ok_or(ParsingError::InvalidValue)?,
+    };
+    if is_valid_value(value) {
+        Ok(value)
+    } else {
+        Err(ParsingError::InvalidValue)
+    }
+}
+
+/// Powers of ten that can be represented exactly as `f64`.
+const POWERS_OF_TEN: [f64; 23] = [
+    1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
+    1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+];
+
+/// Slow path of the `FromStr` implementation of `SpecificSize` for input
+/// containing non-ASCII characters.
+#[cold]
+fn parse_unicode<M: Multiple>(input: &str) -> Result<SpecificSize<M>, ParsingError> {
+    let (value, multiple) = split_unicode(input)?;
+    let multiple = multiple.parse()?;
+    Ok(M::from_any(value, multiple))
+}
+
+/// Same as [`split_size`] for input containing non-ASCII characters.
+#[cold]
+fn split_unicode(input: &str) -> Result<(f64, &str), ParsingError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(ParsingError::EmptyInput);
+    }
+
+    let multiple_index = input
+        .char_indices()
+        .find(|(_, c)| !(c.is_numeric() || *c == '.'))
+        .map(|(i, _)| i)
+        .ok_or(ParsingError::MissingMultiple)?;
+    if multiple_index == 0 {
+        return Err(ParsingError::MissingValue);
+    }
+
+    let (value, multiple) = &input.split_at(multiple_index);
+    let value = value.parse().map_err(|_| ParsingError::InvalidValue)?;
+
+    if is_valid_value(value) {
+        Ok((value, multiple.trim()))
+    } else {
+        Err(ParsingError::InvalidValue)
+    }
+}
+
+/// Same as `str::trim` for ASCII input.
+fn trim_ascii_whitespace(input: &[u8]) -> &[u8] {
+    // Unlike `u8::is_ascii_whitespace` this includes vertical tab (0x0B),
+    // like `char::is_whitespace`.
+    let is_whitespace = |b: &u8| matches!(b, b' ' | b'\t'..=b'\r');
+    let start = input.iter().position(|b| !is_whitespace(b));
+    let end = input.iter().rposition(|b| !is_whitespace(b));
+    match (start, end) {
+        (Some(start), Some(end)) => &input[start..=end],
+        _ => &[],
+    }
+}
+
+impl SpecificSize<Any> {
+    /// Parse a size in a constant, see the [`size!`] macro.
+    ///
+    /// This follows the same rules as the [`FromStr`] implementation, but
+    /// only accepts ASCII digits and whitespace.
+    ///
+    /// # Precision
+    ///
+    /// Unlike the [`FromStr`] implementation the value is not always
+    /// correctly rounded. The value is only guaranteed to be the same if its
+    /// digits (ignoring the decimal point) form a number of at most 2^53,
+    /// i.e. up to 15 significant digits, and it has at most 22 decimals.
+    /// Otherwise digits that don't fit in 64 bits are dropped and the value
+    /// is scaled using multiple roundings, which can make it differ in the
+    /// last bits, e.g. "123456789012345678901 B" results in
+    /// 123456789012345670000 bytes, rather than 123456789012345680000 bytes.
+    ///
+    /// [`size!`]: macro.size.html
+    /// [`FromStr`]: https://doc.rust-lang.org/nightly/core/str/trait.FromStr.html
+    pub const fn parse_const(input: &str) -> Result<Size, ParsingError> {
+        let input = input.as_bytes().trim_ascii();
         if input.is_empty() {
             return Err(ParsingError::EmptyInput);
         }
 
-        let multiple_index = input
-            .chars()
-            .position(|c| !(c.is_numeric() || c == '.'))
-            .ok_or(ParsingError::MissingMultiple)?;
-        if multiple_index == 0 {
+        // Digits that don't fit in `mantissa` are dropped, adjusting
+        // `exponent` for the dropped digits before the decimal point.
+        let mut mantissa: u64 = 0;
+        let mut exponent: i32 = 0;
+        let mut digits = 0;
+        let mut dots = 0;
+        let mut multiple_index = 0;
+        while multiple_index < input.len() {
+            match input[multiple_index] {
+                digit @ b'0'..=b'9' => {
+                    if mantissa < (u64::MAX - 9) / 10 {
+                        mantissa = (mantissa * 10) + (digit - b'0') as u64;
+                        if dots != 0 {
+                            exponent -= 1;
+                        }
+                    } else if dots == 0 {
+                        exponent += 1;
+                    }
+                    digits += 1;
+                }
+                b'.' => dots += 1,
+                _ => break,
+            }
+            multiple_index += 1;
+        }
+        if multiple_index == input.len() {
+            return Err(ParsingError::MissingMultiple);
+        } else if multiple_index == 0 {
             return Err(ParsingError::MissingValue);
+        } else if digits == 0 || dots > 1 {
+            return Err(ParsingError::InvalidValue);
         }
 
-        let (value, multiple) = &input.split_at(multiple_index);
-        let value = value.parse().map_err(|_| ParsingError::InvalidValue)?;
+        // For values up to 2^53 with up to 22 decimals this is exact, as both
+        // numbers can be represented exactly.
+        let mut value = mantissa as f64;
+        while exponent > 0 {
+            let n = if exponent > 22 { 22 } else { exponent };
+            value *= pow10(n);
+            exponent -= n;
+        }
+        while exponent < 0 {
+            let n = if exponent < -22 { 22 } else { -exponent };
+            value /= pow10(n);
+            exponent += n;
+        }
+        if !is_valid_value(value) {
+            return Err(ParsingError::InvalidValue);
+        }
 
-        if is_valid_value(value) {
-            let multiple = multiple.trim().parse()?;
-            Ok(M::from_any(value, multiple))
-        } else {
-            Err(ParsingError::InvalidValue)
+        let (_, multiple) = input.split_at(multiple_index);
+        match Any::from_symbol(multiple.trim_ascii()) {
+            Some(multiple) => Ok(SpecificSize { value, multiple }),
+            None => Err(ParsingError::InvalidMultiple),
         }
     }
 }
 
+/// Returns `10^n`, exact for `n <= 22`.
+const fn pow10(n: i32) -> f64 {
+    let mut value = 1.0;
+    let mut i = 0;
+    while i < n {
+        value *= 10.0;
+        i += 1;
+    }
+    value
+}
+
+/// Parse a size at compile time, failing compilation if it's invalid.
+///
+/// This returns a [`Size`], use [`SpecificSize::from_size`] (available for
+/// all multiple types) to convert it into a specific multiple. The size is
+/// parsed using [`SpecificSize::parse_const`], see its documentation for the
+/// precision of the value.
+///
+/// ```
+/// # extern crate human_size;
+/// # fn main() {
+/// use human_size::{Mebibyte, Size, SpecificSize, size};
+///
+/// const MAX_BODY: Size = size!("10 MiB");
+/// const MAX_BODY_MIB: SpecificSize<Mebibyte> = SpecificSize::<Mebibyte>::from_size(size!("10240 KiB"));
+///
+/// assert_eq!(MAX_BODY.to_string(), "10 MiB");
+/// assert_eq!(MAX_BODY_MIB.to_string(), "10 MiB");
+/// # }
+/// ```
+///
+/// Invalid sizes fail compilation.
+///
+/// ```compile_fail
+/// # extern crate human_size;
+/// # fn main() {
+/// let size = human_size::size!("10 MB MB");
+/// # }
+/// ```
+///
+/// [`Size`]: type.Size.html
+/// [`SpecificSize::from_size`]: struct.SpecificSize.html#method.from_size
+/// [`SpecificSize::parse_const`]: struct.SpecificSize.html#method.parse_const
+#[macro_export]
+macro_rules! size {
+    ($input: expr) => {{
+        const SIZE: $crate::Size = match $crate::Size::parse_const($input) {
+            ::core::result::Result::Ok(size) => size,
+            ::core::result::Result::Err(err) => ::core::panic!("{}", err.as_str()),
+        };
+        SIZE
+    }};
+}
+
 #[cfg(feature = "enable-serde")]
 impl<'de, M> serde_core::Deserialize<'de> for SpecificSize<M>
 where
@@ -297,9 +1300,8 @@ where
     where
         D: serde_core::Deserializer<'de>,
     {
-        use std::marker::PhantomData;
-
-        use serde_core::de::{Error, Visitor};
+        use core::marker::PhantomData;
+        use serde_core::de::{Error, SeqAccess, Unexpected, Visitor};
 
         struct SpecificSizeVisitor<M>(PhantomData<M>);
 
@@ -316,30 +1318,85 @@ where
             {
                 s.parse().map_err(Error::custom)
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let value: f64 = seq
+                    .next_element()?
+                    .ok_or_else(|| Error::invalid_length(0, &self))?;
+                let tag: u8 = seq
+                    .next_element()?
+                    .ok_or_else(|| Error::invalid_length(1, &self))?;
+                let multiple = Any::from_tag(tag).ok_or_else(|| {
+                    Error::invalid_value(Unexpected::Unsigned(tag.into()), &"multiple")
+                })?;
+                if is_valid_value(value) {
+                    Ok(M::from_any(value, multiple))
+                } else {
+                    Err(Error::custom(ParsingError::InvalidValue))
+                }
+            }
         }
 
-        deserializer.deserialize_str(SpecificSizeVisitor(PhantomData))
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(SpecificSizeVisitor(PhantomData))
+        } else if size_of::<M>() == 0 {
+            // See the `Serialize` implementation.
+            let value = f64::deserialize(deserializer)?;
+            if is_valid_value(value) {
+                Ok(from_value(value, Any::Byte))
+            } else {
+                Err(Error::custom(ParsingError::InvalidValue))
+            }
+        } else {
+            deserializer.deserialize_tuple(2, SpecificSizeVisitor(PhantomData))
+        }
     }
 }
 
+/// For human readable formats, e.g. JSON, the size is serialized as string
+/// using its `Display` implementation, e.g. `"10 MB"`.
+///
+/// For binary formats, e.g. bincode or postcard, a more compact form is used.
+/// For zero sized multiples, e.g. [`Kilobyte`], only the value is serialized
+/// as `f64`, as the multiple is defined by the type. For all other multiples,
+/// e.g. [`Any`], it's serialized as a tuple of the value (`f64`) and a
+/// single byte tag for the multiple.
+///
+/// [`Kilobyte`]: multiples/struct.Kilobyte.html
+/// [`Any`]: multiples/enum.Any.html
 #[cfg(feature = "enable-serde")]
 impl<M> serde_core::Serialize for SpecificSize<M>
 where
-    M: Multiple + fmt::Display,
+    M: Multiple + Copy + fmt::Display,
 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde_core::Serializer,
     {
-        // NOTE: this is not the best method as this allocates a string that get
-        // dropped after using it. We could try to use the
-        // `serialize_display_bounded_length` macro from serde.
-        serializer.serialize_str(&self.to_string())
+        use serde_core::ser::SerializeTuple;
+
+        if serializer.is_human_readable() {
+            // Doesn't allocate if the serializer doesn't need to.
+            serializer.collect_str(self)
+        } else if size_of::<M>() == 0 {
+            serializer.serialize_f64(self.value)
+        } else {
+            let (value, multiple) = M::into_any(*self);
+            let mut tuple = serializer.serialize_tuple(2)?;
+            tuple.serialize_element(&value)?;
+            tuple.serialize_element(&multiple.to_tag())?;
+            tuple.end()
+        }
     }
 }
 
 /*
-TODO: Needs specialisation.
+TODO: Needs specialisation. Until then `From` is implemented for conversions
+into `Byte` and between `Any` and the other multiples, see the `multiple!`
+macro.
 impl<M1: Multiple, M2: Multiple> From<SpecificSize<M2>> for SpecificSize<M1> {
     fn from(size: SpecificSize<M2>) -> Self {
         let (value, any) = M2::into_any(size);
@@ -357,10 +1414,14 @@ impl<M> PartialEq for SpecificSize<M> {
 }
 */
 
-/// The allowed margin to consider two floats still equal, after applying the
-/// multiple. Keep in sync with the Notes section of `SpecificSize`.
+/// The allowed margin to consider two floats still equal, in bytes. Keep in
+/// sync with the Notes section of `SpecificSize`.
 const CMP_MARGIN: f64 = 0.000_000_01;
 
+/// The allowed relative difference to consider two floats still equal. Keep
+/// in sync with the Notes section of `SpecificSize`.
+const CMP_RELATIVE: f64 = 0.000_000_000_001;
+
 impl<LM, RM> PartialEq<SpecificSize<RM>> for SpecificSize<LM>
 where
     LM: Multiple + Copy,
@@ -371,8 +1432,18 @@ where
         // To negate the loss in accuracy we check if the difference between the
         // values is really low and consider that the same.
         let (left, right) = into_same_multiples(*self, *other);
-        let diff = left - right;
-        diff.abs() < CMP_MARGIN
+        if left == right {
+            return true;
+        }
+        // The difference caused by rounding grows with the values, so we
+        // allow a small relative difference.
+        let diff = (left - right).abs();
+        if diff <= CMP_RELATIVE * left.abs().max(right.abs()) {
+            return true;
+        }
+        // For values close to zero that doesn't work, so we also allow a small
+        // absolute difference, in bytes to keep it symmetric.
+        (self.bytes() - other.bytes()).abs() < CMP_MARGIN
     }
 }
 
@@ -393,11 +1464,218 @@ where
     RM: Multiple + Copy,
 {
     fn partial_cmp(&self, other: &SpecificSize<RM>) -> Option<Ordering> {
+        // Must be consistent with the `PartialEq` implementation.
+        if self == other {
+            return Some(Ordering::Equal);
+        }
         let (left, right) = into_same_multiples(*self, *other);
         left.partial_cmp(&right)
     }
 }
 
+/// Tolerance used in comparing sizes, see [`SpecificSize::approx_eq`].
+///
+/// [`SpecificSize::approx_eq`]: struct.SpecificSize.html#method.approx_eq
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Tolerance {
+    /// Maximum difference in bytes.
+    Absolute(f64),
+    /// Maximum difference relative to the largest size, e.g. `0.01` allows
+    /// the sizes to differ by 1%.
+    Relative(f64),
+    /// Maximum number of representable floating point numbers between the
+    /// sizes in bytes, also known as units in the last place.
+    Ulps(u64),
+}
+
+/// Returns the number of representable floating point numbers between `left`
+/// and `right`.
+fn ulps(left: f64, right: f64) -> u64 {
+    /// Maps the float to an integer with the same ordering.
+    fn ordered(value: f64) -> i64 {
+        let bits = value.to_bits() as i64;
+        if bits < 0 { i64::MIN - bits } else { bits }
+    }
+    ordered(left).abs_diff(ordered(right))
+}
+
+/// Size with a total ordering, usable as key in a `BTreeMap` or `HashMap`.
+///
+/// Unlike [`SpecificSize`] sizes are compared (and hashed) exactly, using the
+/// size in bytes. This means that the ordering is consistent and transitive,
+/// but that sizes with different multiples might not be equal due to rounding
+/// errors, e.g. "1.1 kB" and "1100 B".
+///
+/// ```
+/// # extern crate human_size;
+/// # fn main() {
+/// use std::collections::BTreeMap;
+///
+/// use human_size::{OrdSize, Size};
+///
+/// let mut files = BTreeMap::new();
+/// for (name, size) in [("a.txt", "2 KiB"), ("b.txt", "1 MB"), ("c.txt", "2048 B")] {
+///     let size: Size = size.parse().unwrap();
+///     files.entry(OrdSize::new(size)).or_insert_with(Vec::new).push(name);
+/// }
+///
+/// let (size, names) = files.first_key_value().unwrap();
+/// assert_eq!(size.to_string(), "2 KiB");
+/// assert_eq!(names, &["a.txt", "c.txt"]);
+/// # }
+/// ```
+///
+/// [`SpecificSize`]: struct.SpecificSize.html
+#[derive(Copy, Clone, Debug)]
+pub struct OrdSize<M = Any> {
+    size: SpecificSize<M>,
+    /// Size in bytes, never negative zero.
+    bytes: f64,
+}
+
+impl<M: Multiple + Copy> OrdSize<M> {
+    /// Create a new `OrdSize`.
+    pub fn new(size: SpecificSize<M>) -> OrdSize<M> {
+        // Adding zero turns negative zero into positive zero.
+        let bytes = size.bytes() + 0.0;
+        OrdSize { size, bytes }
+    }
+
+    /// Returns the size.
+    pub fn size(self) -> SpecificSize<M> {
+        self.size
+    }
+
+    /// Returns the size in bytes used for comparing and hashing.
+    pub fn bytes(self) -> f64 {
+        self.bytes
+    }
+}
+
+impl<M: Multiple + Copy> From<SpecificSize<M>> for OrdSize<M> {
+    fn from(size: SpecificSize<M>) -> OrdSize<M> {
+        OrdSize::new(size)
+    }
+}
+
+impl<M> PartialEq for OrdSize<M> {
+    fn eq(&self, other: &OrdSize<M>) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<M> Eq for OrdSize<M> {}
+
+impl<M> PartialOrd for OrdSize<M> {
+    fn partial_cmp(&self, other: &OrdSize<M>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<M> Ord for OrdSize<M> {
+    fn cmp(&self, other: &OrdSize<M>) -> Ordering {
+        self.bytes.total_cmp(&other.bytes)
+    }
+}
+
+impl<M> Hash for OrdSize<M> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bytes.to_bits().hash(state);
+    }
+}
+
+impl<M: fmt::Display> fmt::Display for OrdSize<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.size.fmt(f)
+    }
+}
+
+/// Size formatted using the symbols defined by JEDEC, returned by
+/// [`SpecificSize::to_jedec`].
+///
+/// Kibibyte, mebibyte and gibibyte are formatted as "KB", "MB" and "GB", all
+/// other multiples are formatted as usual. Note that "MB" and "GB" are parsed
+/// as megabyte and gigabyte (while "KB" is parsed as kibibyte), use
+/// [`parse_lenient`] with [`UnitSystem::Jedec`] to parse them as multiples of
+/// 1024.
+///
+/// ```
+/// # extern crate human_size;
+/// # fn main() {
+/// use human_size::{Any, SpecificSize};
+///
+/// let size = SpecificSize::new(2048, Any::Mebibyte).unwrap();
+/// assert_eq!(size.to_jedec().to_string(), "2 GB");
+/// assert_eq!(format!("{:.1}", size.to_jedec()), "2.0 GB");
+/// # }
+/// ```
+///
+/// [`SpecificSize::to_jedec`]: struct.SpecificSize.html#method.to_jedec
+/// [`parse_lenient`]: numfmt/fn.parse_lenient.html
+/// [`UnitSystem::Jedec`]: multiples/enum.UnitSystem.html#variant.Jedec
+#[derive(Copy, Clone, Debug)]
+pub struct Jedec {
+    size: Size,
+}
+
+impl Jedec {
+    /// Returns the size, using the binary multiples.
+    pub fn size(self) -> Size {
+        self.size
+    }
+}
+
+impl fmt::Display for Jedec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let symbol = match self.size.multiple {
+            Any::Kibibyte => "KB",
+            Any::Mebibyte => "MB",
+            Any::Gigibyte => "GB",
+            _ => return self.size.fmt(f),
+        };
+        if let Some(precision) = f.precision() {
+            write!(f, "{:.*} {}", precision, self.size.value, symbol)
+        } else {
+            write!(f, "{} {}", self.size.value, symbol)
+        }
+    }
+}
+
+/// Convert `size` into `multiple`, returning the value.
+fn into_multiple<M, M2>(size: SpecificSize<M>, multiple: M2) -> f64
+where
+    M: Multiple,
+    M2: Multiple,
+{
+    let (value, any) = M::into_any(size);
+    convert_value(value, any.multiple_of_bytes(), multiple_of_bytes(multiple))
+}
+
+/// Convert `value` from a multiple of `from` bytes into a multiple of `to`
+/// bytes.
+pub(crate) const fn convert_value(value: f64, from: f64, to: f64) -> f64 {
+    if from == to {
+        return value;
+    }
+    // Going through bytes is more precise, e.g. "10 GiB" becomes "10737.41824
+    // MB" rather than "10737.418239999999 MB", but it can overflow.
+    let bytes = value * from;
+    if bytes.is_finite() {
+        bytes / to
+    } else {
+        value * (from / to)
+    }
+}
+
+/// Returns the number of bytes `multiple` represents.
+fn multiple_of_bytes<M: Multiple>(multiple: M) -> f64 {
+    let (value, any) = M::into_any(SpecificSize {
+        value: 1.0,
+        multiple,
+    });
+    value * any.multiple_of_bytes()
+}
+
 /// Convert the provided `left` and `right` sizes into the same multiples,
 /// returning the values. For example if left is `1 Kilobyte`, and right is
 /// `1000 Byte`, it will return `(1, 1)` (in the multiple of Kilobyte).
@@ -453,6 +1731,49 @@ impl fmt::Display for InvalidValueError {
 
 impl Error for InvalidValueError {}
 
+/// The error returned when converting a [`SpecificSize`] into a number of
+/// bytes, using the [`TryFrom`] trait.
+///
+/// Sizes within the margin used to compare sizes (see the Notes section of
+/// [`SpecificSize`]) of a whole number of bytes are rounded to it.
+///
+/// ```
+/// # extern crate human_size;
+/// # fn main() {
+/// use human_size::{SpecificSize, Byte, Kilobyte, TryFromSizeError};
+///
+/// let size = SpecificSize::new(1.5, Kilobyte).unwrap();
+/// assert_eq!(u64::try_from(size), Ok(1500));
+///
+/// let size = SpecificSize::new(1.5, Byte).unwrap();
+/// assert_eq!(u64::try_from(size), Err(TryFromSizeError::Fractional));
+/// # }
+/// ```
+///
+/// [`SpecificSize`]: struct.SpecificSize.html
+/// [`TryFrom`]: https://doc.rust-lang.org/nightly/core/convert/trait.TryFrom.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TryFromSizeError {
+    /// The size is negative.
+    Negative,
+    /// The size is too large for the integer type.
+    Overflow,
+    /// The size is not a whole number of bytes, e.g. "1.5 B".
+    Fractional,
+}
+
+impl fmt::Display for TryFromSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match *self {
+            TryFromSizeError::Negative => "size is negative",
+            TryFromSizeError::Overflow => "size too large",
+            TryFromSizeError::Fractional => "size is not a whole number of bytes",
+        })
+    }
+}
+
+impl Error for TryFromSizeError {}
+
 /// The error returned when trying to parse a [`SpecificSize`], using the
 /// [`FromStr`] trait.
 ///
@@ -474,15 +1795,23 @@ pub enum ParsingError {
     InvalidMultiple,
 }
 
-impl fmt::Display for ParsingError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.pad(match *self {
+impl ParsingError {
+    /// Returns the description of the error, same as its `Display`
+    /// implementation.
+    pub const fn as_str(self) -> &'static str {
+        match self {
             ParsingError::EmptyInput => "input is empty",
             ParsingError::MissingValue => "no value",
             ParsingError::InvalidValue => "invalid value",
             ParsingError::MissingMultiple => "no multiple",
             ParsingError::InvalidMultiple => "invalid multiple",
-        })
+        }
+    }
+}
+
+impl fmt::Display for ParsingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.as_str())
     }
 }
 
@@ -0,0 +1,382 @@
+//! Command line tool to convert, humanize, compare, sum and sort sizes.
+//!
+//! See `human-size --help` for usage.
+
+use std::cmp::Ordering;
+use std::io::{self, BufRead};
+use std::num::NonZeroUsize;
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand};
+use human_size::numfmt::{Filter, Padding};
+use human_size::sort::SortKey;
+use human_size::{Any, Byte, Multiple, ParsingError, Size, SpecificSize, UnitSystem};
+use regex::Regex;
+use serde_json::{Value, json};
+
+/// Convert, humanize, compare, sum and sort sizes.
+#[derive(Debug, Parser)]
+#[command(name = "human-size", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Print the output as JSON.
+    #[arg(long, global = true)]
+    json: bool,
+    /// Number of digits after the decimal point.
+    #[arg(long, global = true)]
+    precision: Option<usize>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Convert a size into another multiple, e.g. `convert "10 GiB" --to MB`.
+    Convert {
+        /// Size to convert.
+        size: String,
+        /// Multiple to convert into.
+        #[arg(long)]
+        to: String,
+    },
+    /// Humanize byte counts, e.g. `humanize 1572864` prints "1.572864 MB".
+    ///
+    /// If no byte counts are provided they are read from standard input, one
+    /// per line.
+    Humanize {
+        /// Number of bytes.
+        bytes: Vec<String>,
+        /// Use multiples of 1024, e.g. MiB, rather than multiples of 1000.
+        #[arg(long)]
+        binary: bool,
+    },
+    /// Compare two sizes.
+    Compare {
+        /// Left side of the comparison.
+        left: String,
+        /// Right side of the comparison.
+        right: String,
+    },
+    /// Sum sizes.
+    ///
+    /// If no sizes are provided they are read from standard input, one per
+    /// line.
+    Sum {
+        /// Sizes to sum.
+        sizes: Vec<String>,
+        /// Multiple of the total, defaults to the best fitting multiple.
+        #[arg(long)]
+        to: Option<String>,
+        /// Use multiples of 1024, e.g. MiB, rather than multiples of 1000.
+        #[arg(long, conflicts_with = "to")]
+        binary: bool,
+    },
+    /// Sort lines by size, similar to `sort -h`.
+    ///
+    /// If no lines are provided they are read from standard input. Lines that
+    /// can't be parsed are sorted first.
+    Sort {
+        /// Lines to sort.
+        lines: Vec<String>,
+        /// Sort by the given whitespace separated field, starting at 1, rather
+        /// than the entire line.
+        #[arg(long)]
+        field: Option<NonZeroUsize>,
+        /// Reverse the order.
+        #[arg(long, short)]
+        reverse: bool,
+        /// Interpret single letter multiples, e.g. "K", as multiples of 1024
+        /// rather than 1000.
+        #[arg(long)]
+        binary: bool,
+    },
+    /// Rewrite numbers of bytes in text into human readable sizes, or the
+    /// reverse, similar to numfmt(1).
+    ///
+    /// Reads from standard input and writes to standard output.
+    Numfmt(NumfmtArgs),
+}
+
+#[derive(Debug, Args)]
+struct NumfmtArgs {
+    /// Field to rewrite, starting at 1. Can be provided multiple times.
+    /// Defaults to the first field.
+    #[arg(long)]
+    field: Vec<NonZeroUsize>,
+    /// Rewrite all matches of a regex, or the first capture group of it,
+    /// rather than fields.
+    #[arg(long, conflicts_with = "field")]
+    regex: Option<Regex>,
+    /// Delimiter between fields, defaults to whitespace.
+    #[arg(long, short)]
+    delimiter: Option<char>,
+    /// Rewrite human readable sizes, e.g. "1.5G", into bytes.
+    #[arg(long)]
+    from_human: bool,
+    /// Use multiples of 1024, e.g. MiB, rather than multiples of 1000. When
+    /// using `--from-human` this applies to single letter multiples, e.g.
+    /// "K" and "M".
+    #[arg(long)]
+    binary: bool,
+    /// Pad rewritten fields to the given width, right aligned. A negative
+    /// width left aligns the field.
+    #[arg(long, allow_negative_numbers = true, conflicts_with = "preserve_width")]
+    padding: Option<isize>,
+    /// Keep the width of the original fields.
+    #[arg(long)]
+    preserve_width: bool,
+}
+
+/// Error returned by the commands.
+#[derive(Debug)]
+enum Error {
+    /// Invalid input, with the input.
+    Parsing(ParsingError, String),
+    /// Sum of the sizes overflowed.
+    Overflow,
+    /// Error reading standard input or writing standard output.
+    Io(io::Error),
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(&cli) {
+        Ok(Some(output)) => {
+            if cli.json {
+                println!("{}", output.json);
+            } else {
+                println!("{}", output.text);
+            }
+            ExitCode::SUCCESS
+        }
+        Ok(None) => ExitCode::SUCCESS,
+        Err(err) => {
+            let msg = match err {
+                Error::Parsing(err, input) => format!("{}: '{}'", err, input),
+                Error::Overflow => "sum of sizes overflowed".to_owned(),
+                Error::Io(err) => format!("I/O error: {}", err),
+            };
+            if cli.json {
+                println!("{}", json!({ "error": msg }));
+            } else {
+                eprintln!("human-size: {}", msg);
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Output of a command, in both text and JSON form.
+struct Output {
+    text: String,
+    json: Value,
+}
+
+/// Run the command, returns `None` if the command already wrote its output.
+fn run(cli: &Cli) -> Result<Option<Output>, Error> {
+    let fmt = |size: Size| format_size(size, cli.precision);
+    let output = match &cli.command {
+        Command::Convert { size, to } => {
+            let size = parse_size(size)?;
+            let to = parse_multiple(to)?;
+            let size = convert(size, to);
+            Output {
+                text: fmt(size),
+                json: size_json(size, cli.precision),
+            }
+        }
+        Command::Humanize { bytes, binary } => {
+            let system = unit_system(*binary);
+            let sizes = inputs(bytes)?
+                .iter()
+                .map(|bytes| parse_bytes(bytes).map(|size| size.to_system(system)))
+                .collect::<Result<Vec<_>, _>>()?;
+            Output {
+                text: sizes
+                    .iter()
+                    .map(|size| fmt(*size))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                json: sizes
+                    .iter()
+                    .map(|size| size_json(*size, cli.precision))
+                    .collect(),
+            }
+        }
+        Command::Compare { left, right } => {
+            let left = parse_size(left)?;
+            let right = parse_size(right)?;
+            let (operator, ordering) = match left.partial_cmp(&right) {
+                Some(Ordering::Less) => ("<", "less"),
+                Some(Ordering::Equal) => ("=", "equal"),
+                Some(Ordering::Greater) => (">", "greater"),
+                None => unreachable!("sizes always have a valid value"),
+            };
+            Output {
+                text: format!("{} {} {}", fmt(left), operator, fmt(right)),
+                json: json!({
+                    "left": size_json(left, cli.precision),
+                    "right": size_json(right, cli.precision),
+                    "ordering": ordering,
+                }),
+            }
+        }
+        Command::Sum { sizes, to, binary } => {
+            let to = to.as_deref().map(parse_multiple).transpose()?;
+            let sizes = inputs(sizes)?
+                .iter()
+                .map(|size| parse_size(size))
+                .collect::<Result<Vec<_>, _>>()?;
+            let total = sizes
+                .iter()
+                .try_fold(SpecificSize::new(0, Byte).unwrap(), |total, size| {
+                    total.checked_add(*size).ok_or(Error::Overflow)
+                })?;
+            let total = match to {
+                Some(to) => convert(total, to),
+                None => total.to_system(unit_system(*binary)),
+            };
+            Output {
+                text: fmt(total),
+                json: json!({
+                    "total": size_json(total, cli.precision),
+                    "count": sizes.len(),
+                }),
+            }
+        }
+        Command::Sort {
+            lines,
+            field,
+            reverse,
+            binary,
+        } => {
+            let system = unit_system(*binary);
+            let mut lines = inputs(lines)?;
+            lines.sort_by_cached_key(|line| {
+                let token = match field {
+                    Some(field) => line.split_whitespace().nth(field.get() - 1).unwrap_or(""),
+                    None => line,
+                };
+                SortKey::new(token, system)
+            });
+            if *reverse {
+                lines.reverse();
+            }
+            Output {
+                text: lines.join("\n"),
+                json: lines.into(),
+            }
+        }
+        Command::Numfmt(args) => {
+            numfmt(args, cli.precision)?;
+            return Ok(None);
+        }
+    };
+    Ok(Some(output))
+}
+
+fn numfmt(args: &NumfmtArgs, precision: Option<usize>) -> Result<(), Error> {
+    let system = unit_system(args.binary);
+    let mut filter = if args.from_human {
+        Filter::dehumanize(system)
+    } else {
+        Filter::humanize(system)
+    };
+    for field in &args.field {
+        filter = filter.field(field.get());
+    }
+    if let Some(regex) = &args.regex {
+        filter = filter.regex(regex.clone());
+    }
+    if let Some(delimiter) = args.delimiter {
+        filter = filter.delimiter(delimiter);
+    }
+    if let Some(precision) = precision {
+        filter = filter.precision(precision);
+    }
+    filter = match args.padding {
+        _ if args.preserve_width => filter.padding(Padding::Preserve),
+        Some(width) if width < 0 => filter.padding(Padding::Left(width.unsigned_abs())),
+        Some(width) => filter.padding(Padding::Right(width.unsigned_abs())),
+        None => filter,
+    };
+    let stdout = io::stdout();
+    filter
+        .filter(io::stdin().lock(), io::BufWriter::new(stdout.lock()))
+        .map_err(Error::Io)
+}
+
+/// Returns `args`, or if empty all non-empty lines from standard input.
+fn inputs(args: &[String]) -> Result<Vec<String>, Error> {
+    if !args.is_empty() {
+        return Ok(args.to_vec());
+    }
+    let mut lines = Vec::new();
+    for line in io::stdin().lock().lines() {
+        let line = line.map_err(Error::Io)?;
+        let line = line.trim();
+        if !line.is_empty() {
+            lines.push(line.to_owned());
+        }
+    }
+    Ok(lines)
+}
+
+fn parse_size(input: &str) -> Result<Size, Error> {
+    input
+        .parse()
+        .map_err(|err| Error::Parsing(err, input.to_owned()))
+}
+
+fn parse_multiple(input: &str) -> Result<Any, Error> {
+    input
+        .trim()
+        .parse()
+        .map_err(|err| Error::Parsing(err, input.to_owned()))
+}
+
+fn parse_bytes(input: &str) -> Result<SpecificSize<Byte>, Error> {
+    let err = |err| Error::Parsing(err, input.to_owned());
+    let bytes: f64 = input
+        .trim()
+        .parse()
+        .map_err(|_| err(ParsingError::InvalidValue))?;
+    SpecificSize::new(bytes, Byte).map_err(|_| err(ParsingError::InvalidValue))
+}
+
+fn unit_system(binary: bool) -> UnitSystem {
+    if binary {
+        UnitSystem::Binary
+    } else {
+        UnitSystem::Decimal
+    }
+}
+
+fn format_size(size: Size, precision: Option<usize>) -> String {
+    match precision {
+        Some(precision) => format!("{:.*}", precision, size),
+        None => size.to_string(),
+    }
+}
+
+/// Convert `size` into `multiple`, ignoring rounding errors when converting
+/// into bytes, e.g. "32.3 kB" becomes "32300 B" rather than
+/// "32299.999999999996 B".
+fn convert<M: Multiple + Copy>(size: SpecificSize<M>, multiple: Any) -> Size {
+    match size.try_to_bytes() {
+        Ok(bytes) if multiple == Any::Byte => SpecificSize::new(bytes as f64, multiple).unwrap(),
+        _ => size.to_multiple(multiple),
+    }
+}
+
+fn size_json(size: Size, precision: Option<usize>) -> Value {
+    let bytes = match size.try_to_bytes() {
+        Ok(bytes) => json!(bytes),
+        Err(_) => json!(size.to_multiple(Any::Byte).value()),
+    };
+    json!({
+        "value": size.value(),
+        "multiple": size.multiple().to_string(),
+        "bytes": bytes,
+        "human": format_size(size, precision),
+    })
+}
@@ -1,16 +1,17 @@
 //! Module containing all multiples.
 //!
 //! All types defined here implement [`Multiple`]. Because all types defined
-//! here, expect for `Any`, don't have any fields they are always zero sized.
+//! here, expect for `Any` and `Custom`, don't have any fields they are always
+//! zero sized.
 //! Meaning that for example `SpecificSize<Byte>` has the same size as `f64`
 //! (the type used as underlying value).
 //!
 //! [`Multiple`]: ../trait.Multiple.html
 
-use std::fmt;
-use std::str::FromStr;
+use core::fmt;
+use core::str::FromStr;
 
-use super::{Multiple, ParsingError, SpecificSize};
+use super::{Multiple, ParsingError, Size, SpecificSize, convert_value};
 
 /// Macro used to allow the `concat` macro to be used inside the doc attribute.
 ///
@@ -26,21 +27,73 @@ macro_rules! doc_comment {
 /// Macro to create a multiple.
 ///
 /// This multiple will be a zero sized struct that implements `Multiple` and
-/// `fmt::Display`.
+/// `fmt::Display`, with the `BYTES` and `BYTES_U128` constants.
 macro_rules! multiple {
+    (Byte, $size:expr, $str:expr) => {
+        multiple!(Byte, $size, $str, "Byte", stringify!($size));
+    };
     ($name:ident, $size:expr, $str:expr) => {
         multiple!($name, $size, $str, stringify!($name), stringify!($size));
+
+        impl From<SpecificSize<$name>> for SpecificSize<Byte> {
+            fn from(size: SpecificSize<$name>) -> SpecificSize<Byte> {
+                Byte::from_any(size.value, Any::$name)
+            }
+        }
     };
     ($name:ident, $size:expr, $str:expr, $sname:expr, $ssize:expr) => {
         doc_comment! {
             concat!("Multiple representing a ", $sname, ".\n\n",
                     "Represents a size of `value * ", $ssize,
-                    "`. When parsing this multiple from text it expects `",
+                    "` bytes. When parsing this multiple from text it expects `",
                     $str, "`."),
             #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+            #[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+            #[cfg_attr(feature = "rkyv", rkyv(compare(PartialEq), derive(Debug)))]
             pub struct $name;
         }
 
+        impl $name {
+            /// The number of bytes this multiple represents.
+            pub const BYTES: f64 = $name::BYTES_U128 as f64;
+            /// The exact number of bytes this multiple represents.
+            pub const BYTES_U128: u128 = $size;
+        }
+
+        impl SpecificSize<$name> {
+            /// Create a new size with this multiple, usable in constants.
+            ///
+            /// # Panics
+            ///
+            /// This panics if `value` is not valid, see
+            /// [`SpecificSize::new_const`].
+            ///
+            /// [`SpecificSize::new_const`]: ../struct.SpecificSize.html#method.new_const
+            pub const fn from_const(value: f64) -> SpecificSize<$name> {
+                SpecificSize::new_const(value, $name)
+            }
+
+            /// Convert a size with a generic multiple into this multiple,
+            /// usable in constants.
+            pub const fn from_size(size: Size) -> SpecificSize<$name> {
+                let value =
+                    convert_value(size.value, size.multiple.multiple_of_bytes(), $name::BYTES);
+                SpecificSize {
+                    value,
+                    multiple: $name,
+                }
+            }
+
+            /// Convert into a size with a generic multiple, usable in
+            /// constants.
+            pub const fn to_size(self) -> Size {
+                SpecificSize {
+                    value: self.value,
+                    multiple: Any::$name,
+                }
+            }
+        }
+
         impl fmt::Display for $name {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
                 f.pad($str)
@@ -49,8 +102,8 @@ macro_rules! multiple {
 
         impl Multiple for $name {
             fn from_any(value: f64, multiple: Any) -> SpecificSize<Self> {
-                let multiply = multiple.multiple_of_bytes() / $size;
-                let value = value * multiply;
+                // Same as `from_size`, so all conversions give the same result.
+                let value = convert_value(value, multiple.multiple_of_bytes(), $name::BYTES);
                 SpecificSize {
                     value,
                     multiple: $name,
@@ -67,30 +120,47 @@ macro_rules! multiple {
                 Any::$name
             }
         }
+
+        impl From<SpecificSize<$name>> for Size {
+            fn from(size: SpecificSize<$name>) -> Size {
+                size.to_size()
+            }
+        }
+
+        impl From<Size> for SpecificSize<$name> {
+            fn from(size: Size) -> SpecificSize<$name> {
+                SpecificSize::<$name>::from_size(size)
+            }
+        }
     };
 }
 
-multiple!(Byte, 1_f64, "B");
+multiple!(Byte, 1, "B");
 
 // Multiples of 1000.
-multiple!(Kilobyte, 1000_f64.powi(1), "kB");
-multiple!(Megabyte, 1000_f64.powi(2), "MB");
-multiple!(Gigabyte, 1000_f64.powi(3), "GB");
-multiple!(Terabyte, 1000_f64.powi(4), "TB");
-multiple!(Petabyte, 1000_f64.powi(5), "PB");
-multiple!(Exabyte, 1000_f64.powi(6), "EB");
-multiple!(Zettabyte, 1000_f64.powi(7), "ZB");
-multiple!(Yottabyte, 1000_f64.powi(8), "YB");
+multiple!(Kilobyte, 1000_u128.pow(1), "kB");
+multiple!(Megabyte, 1000_u128.pow(2), "MB");
+multiple!(Gigabyte, 1000_u128.pow(3), "GB");
+multiple!(Terabyte, 1000_u128.pow(4), "TB");
+multiple!(Petabyte, 1000_u128.pow(5), "PB");
+multiple!(Exabyte, 1000_u128.pow(6), "EB");
+multiple!(Zettabyte, 1000_u128.pow(7), "ZB");
+multiple!(Yottabyte, 1000_u128.pow(8), "YB");
+multiple!(Ronnabyte, 1000_u128.pow(9), "RB");
+multiple!(Quettabyte, 1000_u128.pow(10), "QB");
 
 // Multiples of 1024.
-multiple!(Kibibyte, 1024_f64.powi(1), "KiB");
-multiple!(Mebibyte, 1024_f64.powi(2), "MiB");
-multiple!(Gigibyte, 1024_f64.powi(3), "GiB");
-multiple!(Tebibyte, 1024_f64.powi(4), "TiB");
-multiple!(Pebibyte, 1024_f64.powi(5), "PiB");
-multiple!(Exbibyte, 1024_f64.powi(6), "EiB");
-multiple!(Zebibyte, 1024_f64.powi(7), "ZiB");
-multiple!(Yobibyte, 1024_f64.powi(8), "YiB");
+multiple!(Kibibyte, 1024_u128.pow(1), "KiB");
+multiple!(Mebibyte, 1024_u128.pow(2), "MiB");
+multiple!(Gigibyte, 1024_u128.pow(3), "GiB");
+multiple!(Tebibyte, 1024_u128.pow(4), "TiB");
+multiple!(Pebibyte, 1024_u128.pow(5), "PiB");
+multiple!(Exbibyte, 1024_u128.pow(6), "EiB");
+multiple!(Zebibyte, 1024_u128.pow(7), "ZiB");
+multiple!(Yobibyte, 1024_u128.pow(8), "YiB");
+// Not yet part of the IEC standard.
+multiple!(Robibyte, 1024_u128.pow(9), "RiB");
+multiple!(Quebibyte, 1024_u128.pow(10), "QiB");
 
 /// A multiple which can represent all multiples.
 ///
@@ -100,6 +170,11 @@ multiple!(Yobibyte, 1024_f64.powi(8), "YiB");
 ///
 /// For documentation of each variant see the equivalent struct in this module.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "rkyv", rkyv(compare(PartialEq), derive(Debug)))]
 #[allow(missing_docs)]
 #[non_exhaustive]
 pub enum Any {
@@ -112,6 +187,8 @@ pub enum Any {
     Exabyte,
     Zettabyte,
     Yottabyte,
+    Ronnabyte,
+    Quettabyte,
     Kibibyte,
     Mebibyte,
     Gigibyte,
@@ -120,6 +197,8 @@ pub enum Any {
     Exbibyte,
     Zebibyte,
     Yobibyte,
+    Robibyte,
+    Quebibyte,
 }
 
 impl Multiple for Any {
@@ -133,74 +212,163 @@ impl Multiple for Any {
 }
 
 impl Any {
-    pub(crate) fn multiple_of_bytes(self) -> f64 {
+    /// All multiples, from small to large, first the decimal multiples
+    /// followed by the binary multiples.
+    const ALL: [Any; 21] = [
+        Any::Byte,
+        Any::Kilobyte,
+        Any::Megabyte,
+        Any::Gigabyte,
+        Any::Terabyte,
+        Any::Petabyte,
+        Any::Exabyte,
+        Any::Zettabyte,
+        Any::Yottabyte,
+        Any::Ronnabyte,
+        Any::Quettabyte,
+        Any::Kibibyte,
+        Any::Mebibyte,
+        Any::Gigibyte,
+        Any::Tebibyte,
+        Any::Pebibyte,
+        Any::Exbibyte,
+        Any::Zebibyte,
+        Any::Yobibyte,
+        Any::Robibyte,
+        Any::Quebibyte,
+    ];
+
+    /// Returns all multiples, starting with `Byte`, followed by the decimal
+    /// multiples and the binary multiples, both from small to large.
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use human_size::Any;
+    ///
+    /// let symbols: Vec<&str> = Any::all().iter().map(|m| m.symbol()).collect();
+    /// assert_eq!(&symbols[..4], ["B", "kB", "MB", "GB"]);
+    /// # }
+    /// ```
+    pub const fn all() -> &'static [Any] {
+        &Any::ALL
+    }
+
+    /// Returns the number of bytes the multiple represents, e.g. 1000 for
+    /// `Kilobyte`.
+    ///
+    /// For large multiples this is not exact, see
+    /// [`multiple_of_bytes_u128`](#method.multiple_of_bytes_u128).
+    pub const fn multiple_of_bytes(self) -> f64 {
         match self {
-            Any::Byte => 1_f64,
+            Any::Byte => Byte::BYTES,
 
-            Any::Kilobyte => 1000_f64,
-            Any::Megabyte => 1000_f64.powi(2),
-            Any::Gigabyte => 1000_f64.powi(3),
-            Any::Terabyte => 1000_f64.powi(4),
-            Any::Petabyte => 1000_f64.powi(5),
-            Any::Exabyte => 1000_f64.powi(6),
-            Any::Zettabyte => 1000_f64.powi(7),
-            Any::Yottabyte => 1000_f64.powi(8),
+            Any::Kilobyte => Kilobyte::BYTES,
+            Any::Megabyte => Megabyte::BYTES,
+            Any::Gigabyte => Gigabyte::BYTES,
+            Any::Terabyte => Terabyte::BYTES,
+            Any::Petabyte => Petabyte::BYTES,
+            Any::Exabyte => Exabyte::BYTES,
+            Any::Zettabyte => Zettabyte::BYTES,
+            Any::Yottabyte => Yottabyte::BYTES,
+            Any::Ronnabyte => Ronnabyte::BYTES,
+            Any::Quettabyte => Quettabyte::BYTES,
 
-            Any::Kibibyte => 1024_f64,
-            Any::Mebibyte => 1024_f64.powi(2),
-            Any::Gigibyte => 1024_f64.powi(3),
-            Any::Tebibyte => 1024_f64.powi(4),
-            Any::Pebibyte => 1024_f64.powi(5),
-            Any::Exbibyte => 1024_f64.powi(6),
-            Any::Zebibyte => 1024_f64.powi(7),
-            Any::Yobibyte => 1024_f64.powi(8),
+            Any::Kibibyte => Kibibyte::BYTES,
+            Any::Mebibyte => Mebibyte::BYTES,
+            Any::Gigibyte => Gigibyte::BYTES,
+            Any::Tebibyte => Tebibyte::BYTES,
+            Any::Pebibyte => Pebibyte::BYTES,
+            Any::Exbibyte => Exbibyte::BYTES,
+            Any::Zebibyte => Zebibyte::BYTES,
+            Any::Yobibyte => Yobibyte::BYTES,
+            Any::Robibyte => Robibyte::BYTES,
+            Any::Quebibyte => Quebibyte::BYTES,
         }
     }
-}
 
-impl FromStr for Any {
-    type Err = ParsingError;
+    /// Returns the exact number of bytes the multiple represents.
+    pub const fn multiple_of_bytes_u128(self) -> u128 {
+        match self {
+            Any::Byte => Byte::BYTES_U128,
 
-    fn from_str(input: &str) -> Result<Any, Self::Err> {
-        // Special case this to mean kibibytes.
-        // FIXME(#10): this is not ideal.
-        if input == "KB" {
-            return Ok(Any::Kibibyte);
-        }
-
-        let units = [
-            ("B", Any::Byte),
-            ("Kb", Any::Kilobyte),
-            ("MB", Any::Megabyte),
-            ("GB", Any::Gigabyte),
-            ("TB", Any::Terabyte),
-            ("PB", Any::Petabyte),
-            ("EB", Any::Exabyte),
-            ("ZB", Any::Zettabyte),
-            ("YB", Any::Yottabyte),
-            ("KIB", Any::Kibibyte),
-            ("MIB", Any::Mebibyte),
-            ("GIB", Any::Gigibyte),
-            ("TIB", Any::Tebibyte),
-            ("PIB", Any::Pebibyte),
-            ("EIB", Any::Exbibyte),
-            ("ZIB", Any::Zebibyte),
-            ("YIB", Any::Yobibyte),
-        ];
-
-        for (name, variant) in units {
-            if input.eq_ignore_ascii_case(name) {
-                return Ok(variant);
-            }
+            Any::Kilobyte => Kilobyte::BYTES_U128,
+            Any::Megabyte => Megabyte::BYTES_U128,
+            Any::Gigabyte => Gigabyte::BYTES_U128,
+            Any::Terabyte => Terabyte::BYTES_U128,
+            Any::Petabyte => Petabyte::BYTES_U128,
+            Any::Exabyte => Exabyte::BYTES_U128,
+            Any::Zettabyte => Zettabyte::BYTES_U128,
+            Any::Yottabyte => Yottabyte::BYTES_U128,
+            Any::Ronnabyte => Ronnabyte::BYTES_U128,
+            Any::Quettabyte => Quettabyte::BYTES_U128,
+
+            Any::Kibibyte => Kibibyte::BYTES_U128,
+            Any::Mebibyte => Mebibyte::BYTES_U128,
+            Any::Gigibyte => Gigibyte::BYTES_U128,
+            Any::Tebibyte => Tebibyte::BYTES_U128,
+            Any::Pebibyte => Pebibyte::BYTES_U128,
+            Any::Exbibyte => Exbibyte::BYTES_U128,
+            Any::Zebibyte => Zebibyte::BYTES_U128,
+            Any::Yobibyte => Yobibyte::BYTES_U128,
+            Any::Robibyte => Robibyte::BYTES_U128,
+            Any::Quebibyte => Quebibyte::BYTES_U128,
         }
+    }
 
-        Err(ParsingError::InvalidMultiple)
+    /// Returns the system the multiple belongs to, or `None` for `Byte`.
+    ///
+    /// This never returns [`UnitSystem::Jedec`], its multiples are the binary
+    /// multiples.
+    ///
+    /// [`UnitSystem::Jedec`]: enum.UnitSystem.html#variant.Jedec
+    pub const fn system(self) -> Option<UnitSystem> {
+        match self {
+            Any::Byte => None,
+            Any::Kilobyte
+            | Any::Megabyte
+            | Any::Gigabyte
+            | Any::Terabyte
+            | Any::Petabyte
+            | Any::Exabyte
+            | Any::Zettabyte
+            | Any::Yottabyte
+            | Any::Ronnabyte
+            | Any::Quettabyte => Some(UnitSystem::Decimal),
+            Any::Kibibyte
+            | Any::Mebibyte
+            | Any::Gigibyte
+            | Any::Tebibyte
+            | Any::Pebibyte
+            | Any::Exbibyte
+            | Any::Zebibyte
+            | Any::Yobibyte
+            | Any::Robibyte
+            | Any::Quebibyte => Some(UnitSystem::Binary),
+        }
     }
-}
 
-impl fmt::Display for Any {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.pad(match *self {
+    /// Returns the exponent of the multiple within its system, e.g. 2 for
+    /// `Megabyte` (1000²) and `Mebibyte` (1024²), or 0 for `Byte`.
+    pub const fn exponent(self) -> u32 {
+        match self {
+            Any::Byte => 0,
+            Any::Kilobyte | Any::Kibibyte => 1,
+            Any::Megabyte | Any::Mebibyte => 2,
+            Any::Gigabyte | Any::Gigibyte => 3,
+            Any::Terabyte | Any::Tebibyte => 4,
+            Any::Petabyte | Any::Pebibyte => 5,
+            Any::Exabyte | Any::Exbibyte => 6,
+            Any::Zettabyte | Any::Zebibyte => 7,
+            Any::Yottabyte | Any::Yobibyte => 8,
+            Any::Ronnabyte | Any::Robibyte => 9,
+            Any::Quettabyte | Any::Quebibyte => 10,
+        }
+    }
+
+    /// Returns the symbol of the multiple, e.g. "kB", as used in formatting.
+    pub const fn symbol(self) -> &'static str {
+        match self {
             Any::Byte => "B",
 
             Any::Kilobyte => "kB",
@@ -211,6 +379,8 @@ impl fmt::Display for Any {
             Any::Exabyte => "EB",
             Any::Zettabyte => "ZB",
             Any::Yottabyte => "YB",
+            Any::Ronnabyte => "RB",
+            Any::Quettabyte => "QB",
 
             Any::Kibibyte => "KiB",
             Any::Mebibyte => "MiB",
@@ -220,6 +390,339 @@ impl fmt::Display for Any {
             Any::Exbibyte => "EiB",
             Any::Zebibyte => "ZiB",
             Any::Yobibyte => "YiB",
+            Any::Robibyte => "RiB",
+            Any::Quebibyte => "QiB",
+        }
+    }
+
+    /// Returns the name of the multiple, e.g. "kilobyte".
+    pub const fn name(self) -> &'static str {
+        match self {
+            Any::Byte => "byte",
+
+            Any::Kilobyte => "kilobyte",
+            Any::Megabyte => "megabyte",
+            Any::Gigabyte => "gigabyte",
+            Any::Terabyte => "terabyte",
+            Any::Petabyte => "petabyte",
+            Any::Exabyte => "exabyte",
+            Any::Zettabyte => "zettabyte",
+            Any::Yottabyte => "yottabyte",
+            Any::Ronnabyte => "ronnabyte",
+            Any::Quettabyte => "quettabyte",
+
+            Any::Kibibyte => "kibibyte",
+            Any::Mebibyte => "mebibyte",
+            Any::Gigibyte => "gibibyte",
+            Any::Tebibyte => "tebibyte",
+            Any::Pebibyte => "pebibyte",
+            Any::Exbibyte => "exbibyte",
+            Any::Zebibyte => "zebibyte",
+            Any::Yobibyte => "yobibyte",
+            Any::Robibyte => "robibyte",
+            Any::Quebibyte => "quebibyte",
+        }
+    }
+}
+
+/// System of multiples.
+///
+/// See [`SpecificSize::to_system`] to convert a size into the best fitting
+/// multiple of a system.
+///
+/// [`SpecificSize::to_system`]: ../struct.SpecificSize.html#method.to_system
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum UnitSystem {
+    /// Multiples of 1000, as defined by the SI, e.g. kilobyte (kB) and
+    /// megabyte (MB).
+    Decimal,
+    /// Multiples of 1024, as defined by the IEC, e.g. kibibyte (KiB) and
+    /// mebibyte (MiB).
+    Binary,
+    /// Multiples of 1024, as defined by JEDEC for memory, e.g. kilobyte (KB)
+    /// and megabyte (MB).
+    ///
+    /// JEDEC only defines kilo, mega and giga, which are the same as the
+    /// kibibyte, mebibyte and gibibyte multiples of the IEC. Because of that
+    /// the IEC multiples are used to represent them, i.e. [`Any::system`]
+    /// never returns this system. Use [`SpecificSize::to_jedec`] to format a
+    /// size using the JEDEC symbols.
+    ///
+    /// [`Any::system`]: enum.Any.html#method.system
+    /// [`SpecificSize::to_jedec`]: ../struct.SpecificSize.html#method.to_jedec
+    Jedec,
+}
+
+impl UnitSystem {
+    /// Alias for [`UnitSystem::Decimal`].
+    ///
+    /// [`UnitSystem::Decimal`]: enum.UnitSystem.html#variant.Decimal
+    pub const SI: UnitSystem = UnitSystem::Decimal;
+    /// Alias for [`UnitSystem::Binary`].
+    ///
+    /// [`UnitSystem::Binary`]: enum.UnitSystem.html#variant.Binary
+    pub const IEC: UnitSystem = UnitSystem::Binary;
+
+    /// Returns all multiples in this system, from small to large.
+    pub(crate) fn multiples(self) -> &'static [Any] {
+        match self {
+            UnitSystem::Decimal => &[
+                Any::Kilobyte,
+                Any::Megabyte,
+                Any::Gigabyte,
+                Any::Terabyte,
+                Any::Petabyte,
+                Any::Exabyte,
+                Any::Zettabyte,
+                Any::Yottabyte,
+                Any::Ronnabyte,
+                Any::Quettabyte,
+            ],
+            UnitSystem::Binary => &[
+                Any::Kibibyte,
+                Any::Mebibyte,
+                Any::Gigibyte,
+                Any::Tebibyte,
+                Any::Pebibyte,
+                Any::Exbibyte,
+                Any::Zebibyte,
+                Any::Yobibyte,
+                Any::Robibyte,
+                Any::Quebibyte,
+            ],
+            UnitSystem::Jedec => &[Any::Kibibyte, Any::Mebibyte, Any::Gigibyte],
+        }
+    }
+}
+
+#[cfg(feature = "enable-serde")]
+impl Any {
+    /// Returns the tag used to represent the multiple in binary formats.
+    ///
+    /// # Notes
+    ///
+    /// These tags are part of the serialized format, so existing tags must
+    /// never change.
+    pub(crate) fn to_tag(self) -> u8 {
+        match self {
+            Any::Byte => 0,
+            Any::Kilobyte => 1,
+            Any::Megabyte => 2,
+            Any::Gigabyte => 3,
+            Any::Terabyte => 4,
+            Any::Petabyte => 5,
+            Any::Exabyte => 6,
+            Any::Zettabyte => 7,
+            Any::Yottabyte => 8,
+            Any::Kibibyte => 9,
+            Any::Mebibyte => 10,
+            Any::Gigibyte => 11,
+            Any::Tebibyte => 12,
+            Any::Pebibyte => 13,
+            Any::Exbibyte => 14,
+            Any::Zebibyte => 15,
+            Any::Yobibyte => 16,
+            Any::Ronnabyte => 17,
+            Any::Quettabyte => 18,
+            Any::Robibyte => 19,
+            Any::Quebibyte => 20,
+        }
+    }
+
+    /// The opposite of `to_tag`, returns `None` for unknown tags.
+    pub(crate) fn from_tag(tag: u8) -> Option<Any> {
+        Any::all()
+            .iter()
+            .copied()
+            .find(|multiple| multiple.to_tag() == tag)
+    }
+}
+
+impl FromStr for Any {
+    type Err = ParsingError;
+
+    fn from_str(input: &str) -> Result<Any, Self::Err> {
+        Any::from_symbol(input.as_bytes()).ok_or(ParsingError::InvalidMultiple)
+    }
+}
+
+impl Any {
+    /// Returns the multiple for `symbol`, ignoring case.
+    pub(crate) const fn from_symbol(symbol: &[u8]) -> Option<Any> {
+        // Special case this to mean kibibytes.
+        // FIXME(#10): this is not ideal.
+        if let [b'K', b'B'] = symbol {
+            return Some(Any::Kibibyte);
+        }
+
+        // All symbols are at most three bytes, lowercase them so we can match
+        // on them directly.
+        let mut lower = [0; 3];
+        let symbol = match symbol {
+            [a] => {
+                lower[0] = a.to_ascii_lowercase();
+                lower.split_at(1).0
+            }
+            [a, b] => {
+                lower[0] = a.to_ascii_lowercase();
+                lower[1] = b.to_ascii_lowercase();
+                lower.split_at(2).0
+            }
+            [a, b, c] => {
+                lower[0] = a.to_ascii_lowercase();
+                lower[1] = b.to_ascii_lowercase();
+                lower[2] = c.to_ascii_lowercase();
+                lower.as_slice()
+            }
+            _ => return None,
+        };
+        // NOTE: keep in sync with `Any::symbol`, which is checked by the
+        // `multiple_metadata` test.
+        Some(match symbol {
+            b"b" => Any::Byte,
+
+            b"kb" => Any::Kilobyte,
+            b"mb" => Any::Megabyte,
+            b"gb" => Any::Gigabyte,
+            b"tb" => Any::Terabyte,
+            b"pb" => Any::Petabyte,
+            b"eb" => Any::Exabyte,
+            b"zb" => Any::Zettabyte,
+            b"yb" => Any::Yottabyte,
+            b"rb" => Any::Ronnabyte,
+            b"qb" => Any::Quettabyte,
+
+            b"kib" => Any::Kibibyte,
+            b"mib" => Any::Mebibyte,
+            b"gib" => Any::Gigibyte,
+            b"tib" => Any::Tebibyte,
+            b"pib" => Any::Pebibyte,
+            b"eib" => Any::Exbibyte,
+            b"zib" => Any::Zebibyte,
+            b"yib" => Any::Yobibyte,
+            b"rib" => Any::Robibyte,
+            b"qib" => Any::Quebibyte,
+            _ => return None,
         })
     }
 }
+
+impl fmt::Display for Any {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.symbol())
+    }
+}
+
+/// A multiple defined at runtime, e.g. blocks of 4096 bytes or sectors of 512
+/// bytes.
+///
+/// Sizes with a custom multiple can be converted to and compared with sizes
+/// of all other multiples. They can be created using [`SpecificSize::new`],
+/// converted using [`SpecificSize::to_multiple`] or parsed using a
+/// [`UnitRegistry`].
+///
+/// ```
+/// # extern crate human_size;
+/// # fn main() {
+/// use human_size::{Custom, Kibibyte, SpecificSize};
+///
+/// const BLOCK: Custom = Custom::new("block", "blocks", 4096.0);
+///
+/// let size = SpecificSize::new(20, BLOCK).unwrap();
+/// assert_eq!(size.to_string(), "20 blocks");
+/// assert_eq!(size, SpecificSize::new(80, Kibibyte).unwrap());
+///
+/// let size = SpecificSize::new(1, Kibibyte).unwrap();
+/// assert_eq!(size.to_multiple(BLOCK).to_string(), "0.25 blocks");
+/// # }
+/// ```
+///
+/// # Notes
+///
+/// Converting a size into `SpecificSize<Custom>` using [`Multiple::from_any`],
+/// e.g. via [`SpecificSize::into`] or parsing, results in a custom multiple
+/// equal to the original multiple, e.g. "1 kB" stays "1 kB".
+///
+/// In binary serialization formats the size is stored in bytes, when
+/// deserializing it's returned as a custom multiple of one byte.
+///
+/// The name and symbol are `&'static str`s, see [`UnitRegistry::register`]
+/// for using names and symbols only known at runtime.
+///
+/// [`SpecificSize::new`]: ../struct.SpecificSize.html#method.new
+/// [`SpecificSize::to_multiple`]: ../struct.SpecificSize.html#method.to_multiple
+/// [`SpecificSize::into`]: ../struct.SpecificSize.html#method.into
+/// [`Multiple::from_any`]: ../trait.Multiple.html#tymethod.from_any
+/// [`UnitRegistry`]: ../registry/struct.UnitRegistry.html
+/// [`UnitRegistry::register`]: ../registry/struct.UnitRegistry.html#method.register
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Custom {
+    name: &'static str,
+    symbol: &'static str,
+    bytes: f64,
+}
+
+impl Custom {
+    /// Create a new custom multiple of `bytes` bytes. `symbol` is used when
+    /// formatting sizes, both `name` and `symbol` are used when parsing.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `bytes` is not a positive, finite number.
+    pub const fn new(name: &'static str, symbol: &'static str, bytes: f64) -> Custom {
+        assert!(
+            bytes > 0.0 && bytes.is_finite(),
+            "bytes must be a positive, finite number"
+        );
+        Custom {
+            name,
+            symbol,
+            bytes,
+        }
+    }
+
+    /// Returns the name of the multiple.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the symbol of the multiple.
+    pub const fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    /// Returns the number of bytes the multiple represents.
+    pub const fn bytes(&self) -> f64 {
+        self.bytes
+    }
+}
+
+impl fmt::Display for Custom {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.symbol)
+    }
+}
+
+impl Multiple for Custom {
+    fn from_any(value: f64, multiple: Any) -> SpecificSize<Self> {
+        SpecificSize {
+            value,
+            multiple: Custom::from(multiple),
+        }
+    }
+
+    fn into_any(size: SpecificSize<Self>) -> (f64, Any) {
+        (size.value * size.multiple.bytes, Any::Byte)
+    }
+}
+
+impl From<Any> for Custom {
+    fn from(multiple: Any) -> Custom {
+        Custom::new(
+            multiple.name(),
+            multiple.symbol(),
+            multiple.multiple_of_bytes(),
+        )
+    }
+}
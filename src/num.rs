@@ -0,0 +1,137 @@
+//! Implementations of traits from [`num-traits`] for [`SpecificSize`].
+//!
+//! All conversions to and from primitive numbers use the size in bytes, e.g.
+//! `ToPrimitive::to_u64` on "1 kB" returns `Some(1000)` and
+//! `FromPrimitive::from_u64(1000)` for `SpecificSize<Kilobyte>` returns "1 kB".
+//!
+//! [`num-traits`]: https://docs.rs/num-traits
+//! [`SpecificSize`]: ../struct.SpecificSize.html
+
+use num_traits::{Bounded, CheckedAdd, CheckedSub, FromPrimitive, ToPrimitive, Zero};
+
+use super::{Any, Multiple, Rounding, SpecificSize, is_valid_value};
+
+/// Create a new size from `bytes`, returning `None` if the value is not
+/// valid.
+fn from_bytes<M: Multiple>(bytes: f64) -> Option<SpecificSize<M>> {
+    if is_valid_value(bytes) {
+        let size = M::from_any(bytes, Any::Byte);
+        is_valid_value(size.value).then_some(size)
+    } else {
+        None
+    }
+}
+
+impl<M: Multiple + Copy> Zero for SpecificSize<M> {
+    fn zero() -> Self {
+        M::from_any(0.0, Any::Byte)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value == 0.0
+    }
+}
+
+/// The bounds are the smallest and largest number of bytes that can be
+/// represented, converted into multiple `M`.
+impl<M: Multiple> Bounded for SpecificSize<M> {
+    fn min_value() -> Self {
+        M::from_any(f64::MIN, Any::Byte)
+    }
+
+    fn max_value() -> Self {
+        M::from_any(f64::MAX, Any::Byte)
+    }
+}
+
+impl<M: Multiple + Copy> CheckedAdd for SpecificSize<M> {
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        SpecificSize::checked_add(*self, *rhs)
+    }
+}
+
+impl<M: Multiple + Copy> CheckedSub for SpecificSize<M> {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        SpecificSize::checked_sub(*self, *rhs)
+    }
+}
+
+/// Converts the size in bytes, rounding towards zero like the implementation
+/// for `f64`, e.g. "1.5 B" returns 1. Like the `TryFrom` implementations
+/// rounding errors are ignored, e.g. "32.3 kB" returns 32300 rather than 32299.
+impl<M: Multiple + Copy> ToPrimitive for SpecificSize<M> {
+    fn to_i64(&self) -> Option<i64> {
+        signed_bytes(*self).and_then(|bytes| i64::try_from(bytes).ok())
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        unsigned_bytes(*self).and_then(|bytes| u64::try_from(bytes).ok())
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        signed_bytes(*self)
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        unsigned_bytes(*self)
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.bytes())
+    }
+}
+
+/// Returns the size in whole bytes, rounded towards zero, or `None` if it
+/// doesn't fit in an `i128`.
+fn signed_bytes<M: Multiple>(size: SpecificSize<M>) -> Option<i128> {
+    let negative = size.value < 0.0;
+    let whole = abs_whole_bytes(size)?;
+    if negative {
+        0_i128.checked_sub_unsigned(whole)
+    } else {
+        i128::try_from(whole).ok()
+    }
+}
+
+/// Returns the size in whole bytes, rounded towards zero, or `None` if it's
+/// negative (after rounding).
+fn unsigned_bytes<M: Multiple>(size: SpecificSize<M>) -> Option<u128> {
+    let negative = size.value < 0.0;
+    let whole = abs_whole_bytes(size)?;
+    (!negative || whole == 0).then_some(whole)
+}
+
+/// Returns the absolute size in whole bytes, rounded towards zero.
+fn abs_whole_bytes<M: Multiple>(size: SpecificSize<M>) -> Option<u128> {
+    let size = SpecificSize {
+        value: size.value.abs(),
+        multiple: size.multiple,
+    };
+    size.whole_bytes_of(Some(Rounding::Down)).ok()
+}
+
+/// Creates a size from a number of bytes, returning `None` if the number is
+/// not a valid value (see [`SpecificSize::new`]).
+///
+/// [`SpecificSize::new`]: ../struct.SpecificSize.html#method.new
+impl<M: Multiple> FromPrimitive for SpecificSize<M> {
+    fn from_i64(n: i64) -> Option<Self> {
+        from_bytes(n as f64)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        from_bytes(n as f64)
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        from_bytes(n as f64)
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        from_bytes(n as f64)
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        from_bytes(n)
+    }
+}
@@ -0,0 +1,408 @@
+//! Humanizing sizes in text, similar to `numfmt(1)`.
+//!
+//! A [`Filter`] rewrites numeric fields in lines of text, e.g. the output of
+//! `ls -l` or a CSV file, into human readable sizes, or the reverse: rewriting
+//! human readable sizes, such as "1.5G", into a number of bytes.
+//!
+//! ```
+//! # extern crate human_size;
+//! # fn main() {
+//! use human_size::UnitSystem;
+//! use human_size::numfmt::{Filter, Padding};
+//!
+//! let filter = Filter::humanize(UnitSystem::Binary).field(2);
+//! assert_eq!(filter.filter_line("file.txt 1572864"), "file.txt 1.5 MiB");
+//!
+//! let filter = Filter::dehumanize(UnitSystem::Binary)
+//!     .delimiter(',')
+//!     .field(2)
+//!     .padding(Padding::Right(12));
+//! assert_eq!(filter.filter_line("file.txt,1.5M"), "file.txt,     1572864");
+//! # }
+//! ```
+//!
+//! [`Filter`]: struct.Filter.html
+
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::str;
+
+#[cfg(feature = "regex")]
+use regex::Regex;
+
+use super::{Any, ParsingError, Size, SpecificSize, UnitSystem};
+
+/// Filter to rewrite sizes in text.
+///
+/// See the [module documentation] for an example.
+///
+/// By default the first field, separated by whitespace, is rewritten. Fields
+/// that can't be parsed are left untouched.
+///
+/// [module documentation]: index.html
+#[derive(Debug, Clone)]
+pub struct Filter {
+    direction: Direction,
+    system: UnitSystem,
+    fields: Vec<usize>,
+    #[cfg(feature = "regex")]
+    regex: Option<Regex>,
+    delimiter: Option<char>,
+    padding: Padding,
+    precision: Option<usize>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Direction {
+    /// Bytes into human readable sizes.
+    Humanize,
+    /// Human readable sizes into bytes.
+    Dehumanize,
+}
+
+/// Padding applied to rewritten fields.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Padding {
+    /// No padding.
+    None,
+    /// Keep the width of the original field, right aligning the rewritten
+    /// field. When fields are separated by whitespace the whitespace before
+    /// the field is included in the width.
+    Preserve,
+    /// Left align the rewritten field, padding it with spaces to at least the
+    /// provided width.
+    Left(usize),
+    /// Right align the rewritten field, padding it with spaces to at least the
+    /// provided width.
+    Right(usize),
+}
+
+impl Filter {
+    /// Create a filter that rewrites numbers of bytes into human readable
+    /// sizes, using the best fitting multiple of `system` (see
+    /// [`SpecificSize::to_system`]).
+    ///
+    /// [`SpecificSize::to_system`]: ../struct.SpecificSize.html#method.to_system
+    pub fn humanize(system: UnitSystem) -> Filter {
+        Filter::new(Direction::Humanize, system)
+    }
+
+    /// Create a filter that rewrites human readable sizes into numbers of
+    /// bytes. See [`parse_lenient`] for the accepted input, `system` is used
+    /// for single letter multiples such as "K" or "M".
+    ///
+    /// [`parse_lenient`]: fn.parse_lenient.html
+    pub fn dehumanize(system: UnitSystem) -> Filter {
+        Filter::new(Direction::Dehumanize, system)
+    }
+
+    fn new(direction: Direction, system: UnitSystem) -> Filter {
+        Filter {
+            direction,
+            system,
+            fields: Vec::new(),
+            #[cfg(feature = "regex")]
+            regex: None,
+            delimiter: None,
+            padding: Padding::None,
+            precision: None,
+        }
+    }
+
+    /// Rewrite field `field`, starting at one for the first field. Can be
+    /// called multiple times to rewrite multiple fields.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `field` is zero.
+    pub fn field(mut self, field: usize) -> Filter {
+        assert!(field != 0, "fields start at one");
+        self.fields.push(field);
+        self
+    }
+
+    /// Rewrite all matches of `regex`, rather then fields. If the regex has a
+    /// capture group only the first group is rewritten.
+    #[cfg(feature = "regex")]
+    pub fn regex(mut self, regex: Regex) -> Filter {
+        self.regex = Some(regex);
+        self
+    }
+
+    /// Use `delimiter` to separate fields, rather than whitespace.
+    pub fn delimiter(mut self, delimiter: char) -> Filter {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    /// Set the padding of rewritten fields.
+    pub fn padding(mut self, padding: Padding) -> Filter {
+        self.padding = padding;
+        self
+    }
+
+    /// Set the number of digits after the decimal point of rewritten fields.
+    pub fn precision(mut self, precision: usize) -> Filter {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Filter all lines from `input`, writing them to `output`.
+    ///
+    /// Lines don't have to be valid UTF-8, but only fields that are valid
+    /// UTF-8 are rewritten.
+    pub fn filter<R, W>(&self, mut input: R, mut output: W) -> io::Result<()>
+    where
+        R: BufRead,
+        W: Write,
+    {
+        let mut line = Vec::new();
+        let mut filtered = Vec::new();
+        loop {
+            line.clear();
+            if input.read_until(b'\n', &mut line)? == 0 {
+                return output.flush();
+            }
+            let end = line
+                .iter()
+                .rposition(|b| !matches!(b, b'\n' | b'\r'))
+                .map_or(0, |i| i + 1);
+            let (content, newline) = line.split_at(end);
+            filtered.clear();
+            match str::from_utf8(content) {
+                Ok(content) => self.filter_into(content, content.as_bytes(), &mut filtered),
+                Err(_) => self.filter_invalid_line(content, &mut filtered),
+            }
+            output.write_all(&filtered)?;
+            output.write_all(newline)?;
+        }
+    }
+
+    /// Filter a single `line`.
+    pub fn filter_line(&self, line: &str) -> String {
+        let mut output = Vec::with_capacity(line.len());
+        self.filter_into(line, line.as_bytes(), &mut output);
+        // Only parts of `line` and rewritten fields are written.
+        String::from_utf8(output).expect("invalid UTF-8 in filtered line")
+    }
+
+    /// Filter a `line` that is not valid UTF-8.
+    fn filter_invalid_line(&self, line: &[u8], output: &mut Vec<u8>) {
+        // Replace all invalid bytes with NUL bytes, which are never part of a
+        // valid size. This keeps the offsets the same, so we can copy the
+        // original bytes for the fields that are not rewritten.
+        let mut valid = String::with_capacity(line.len());
+        for chunk in line.utf8_chunks() {
+            valid.push_str(chunk.valid());
+            valid.extend(chunk.invalid().iter().map(|_| '\0'));
+        }
+        self.filter_into(&valid, line, output);
+    }
+
+    /// Filter `line` into `output`, copying the parts that are not rewritten
+    /// from `original`, which must have the same length as `line`.
+    fn filter_into(&self, line: &str, original: &[u8], output: &mut Vec<u8>) {
+        let mut last = 0;
+        for span in self.spans(line) {
+            let field = &line[span.start..span.end];
+            let Some(rewritten) = self.rewrite(field.trim()) else {
+                continue;
+            };
+            // Only preserving the width replaces the whitespace before the
+            // field.
+            let start = if self.padding == Padding::Preserve {
+                span.prefix
+            } else {
+                span.start
+            };
+            output.extend_from_slice(&original[last..start]);
+            self.pad(output, &rewritten, &line[start..span.end]);
+            last = span.end;
+        }
+        output.extend_from_slice(&original[last..]);
+    }
+
+    /// Returns the spans of the fields to rewrite.
+    fn spans(&self, line: &str) -> Vec<Span> {
+        #[cfg(feature = "regex")]
+        if let Some(regex) = &self.regex {
+            return regex
+                .captures_iter(line)
+                .filter_map(|captures| captures.get(1).or_else(|| captures.get(0)))
+                .map(|m| Span {
+                    prefix: m.start(),
+                    start: m.start(),
+                    end: m.end(),
+                })
+                .collect();
+        }
+
+        let mut spans = Vec::new();
+        let mut field = 0;
+        let mut prefix = 0;
+        let mut start = None;
+        let chars = line.char_indices().chain(Some((line.len(), '\n')));
+        for (i, c) in chars {
+            let is_separator = i == line.len()
+                || match self.delimiter {
+                    Some(delimiter) => c == delimiter,
+                    None => c.is_whitespace(),
+                };
+            match (start, is_separator) {
+                (None, false) => start = Some(i),
+                (Some(s), true) => {
+                    field += 1;
+                    if self.is_selected(field) {
+                        spans.push(Span {
+                            prefix,
+                            start: s,
+                            end: i,
+                        });
+                    }
+                    start = None;
+                    prefix = if self.delimiter.is_some() {
+                        i + c.len_utf8()
+                    } else {
+                        i
+                    };
+                }
+                // Empty field between two delimiters.
+                (None, true) if self.delimiter.is_some() => {
+                    field += 1;
+                    prefix = i + c.len_utf8();
+                }
+                _ => {}
+            }
+        }
+        spans
+    }
+
+    fn is_selected(&self, field: usize) -> bool {
+        if self.fields.is_empty() {
+            field == 1
+        } else {
+            self.fields.contains(&field)
+        }
+    }
+
+    /// Rewrite a single field, returns `None` if the field can't be parsed.
+    fn rewrite(&self, field: &str) -> Option<String> {
+        let size = parse_lenient(field, self.system).ok()?;
+        Some(match self.direction {
+            Direction::Humanize if self.system == UnitSystem::Jedec => self.format(size.to_jedec()),
+            Direction::Humanize => self.format(size.to_system(self.system)),
+            Direction::Dehumanize => self.format(size.to_multiple(Any::Byte).value()),
+        })
+    }
+
+    /// Format a rewritten field, using the configured precision.
+    fn format<T: fmt::Display>(&self, value: T) -> String {
+        match self.precision {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => value.to_string(),
+        }
+    }
+
+    /// Push `rewritten` onto `output`, with padding. `original` is the
+    /// original field, including the whitespace before it when preserving
+    /// the width.
+    fn pad(&self, output: &mut Vec<u8>, rewritten: &str, original: &str) {
+        // Writing to a `Vec` can't fail.
+        let _ = match self.padding {
+            Padding::None => write!(output, "{}", rewritten),
+            Padding::Preserve => {
+                let width = original.chars().count();
+                // Always keep at least one separator between the fields.
+                let separator = original.starts_with(char::is_whitespace) as usize;
+                let width = width.max(rewritten.chars().count() + separator);
+                write!(output, "{:>1$}", rewritten, width)
+            }
+            Padding::Left(width) => write!(output, "{:<1$}", rewritten, width),
+            Padding::Right(width) => write!(output, "{:>1$}", rewritten, width),
+        };
+    }
+}
+
+/// Span of a field in a line.
+#[derive(Debug)]
+struct Span {
+    /// Start of the whitespace before the field.
+    prefix: usize,
+    start: usize,
+    end: usize,
+}
+
+/// Parse a size leniently.
+///
+/// Next to the input accepted by [`SpecificSize`]'s `FromStr`
+/// implementation, this also accepts:
+///
+///  * a number without multiple, which is interpreted as bytes, e.g. "100";
+///  * single letter multiples, as used by tools such as `ls -h` and `du -h`,
+///    e.g. "1.5K" or "10G". Whether these are multiples of 1000 or 1024 is
+///    determined by `system`;
+///  * single letter multiples followed by "i", e.g. "1.5Ki" or "10Gi", which
+///    are always multiples of 1024;
+///  * for [`UnitSystem::Jedec`] the JEDEC multiples "KB", "MB" and "GB", which
+///    are multiples of 1024 (rather than 1000 for "MB" and "GB");
+///  * a sign, e.g. "-100" or "+1.5K".
+///
+/// ```
+/// # extern crate human_size;
+/// # fn main() {
+/// use human_size::{Any, SpecificSize, UnitSystem};
+/// use human_size::numfmt::parse_lenient;
+///
+/// let size = parse_lenient("1.5G", UnitSystem::Decimal).unwrap();
+/// assert_eq!(size, SpecificSize::new(1.5, Any::Gigabyte).unwrap());
+/// let size = parse_lenient("1.5G", UnitSystem::Binary).unwrap();
+/// assert_eq!(size, SpecificSize::new(1.5, Any::Gigibyte).unwrap());
+/// let size = parse_lenient("100", UnitSystem::Binary).unwrap();
+/// assert_eq!(size, SpecificSize::new(100, Any::Byte).unwrap());
+/// let size = parse_lenient("10 MiB", UnitSystem::Decimal).unwrap();
+/// assert_eq!(size, SpecificSize::new(10, Any::Mebibyte).unwrap());
+/// let size = parse_lenient("10 MB", UnitSystem::Jedec).unwrap();
+/// assert_eq!(size, SpecificSize::new(10, Any::Mebibyte).unwrap());
+/// # }
+/// ```
+///
+/// [`SpecificSize`]: ../struct.SpecificSize.html
+/// [`UnitSystem::Jedec`]: ../multiples/enum.UnitSystem.html#variant.Jedec
+pub fn parse_lenient(input: &str, system: UnitSystem) -> Result<Size, ParsingError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(ParsingError::EmptyInput);
+    }
+
+    let (sign, unsigned) = match input.as_bytes()[0] {
+        b'-' => (-1.0, &input[1..]),
+        b'+' => (1.0, &input[1..]),
+        _ => (1.0, input),
+    };
+    let multiple_index = unsigned
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(unsigned.len());
+    if multiple_index == 0 {
+        return Err(ParsingError::MissingValue);
+    }
+    let (value, multiple) = unsigned.split_at(multiple_index);
+    let value: f64 = value.parse().map_err(|_| ParsingError::InvalidValue)?;
+    let multiple = match multiple.trim() {
+        "" => Any::Byte,
+        multiple => parse_short_multiple(multiple, system).map_or_else(|| multiple.parse(), Ok)?,
+    };
+    SpecificSize::new(sign * value, multiple).map_err(|_| ParsingError::InvalidValue)
+}
+
+/// Parse single letter multiples, e.g. "K", or followed by "i", e.g. "Ki", or
+/// the JEDEC multiples, e.g. "KB".
+fn parse_short_multiple(input: &str, system: UnitSystem) -> Option<Any> {
+    let (letter, system) = match input.as_bytes() {
+        [letter] => (letter.to_ascii_uppercase(), system),
+        [letter, b'i'] => (letter.to_ascii_uppercase(), UnitSystem::Binary),
+        [letter, b'B'] if system == UnitSystem::Jedec => (letter.to_ascii_uppercase(), system),
+        _ => return None,
+    };
+    let index = b"KMGTPEZYRQ".iter().position(|l| *l == letter)?;
+    system.multiples().get(index).copied()
+}
@@ -0,0 +1,129 @@
+//! Parsing sizes with custom multiples.
+//!
+//! See [`UnitRegistry`].
+//!
+//! [`UnitRegistry`]: struct.UnitRegistry.html
+
+use alloc::vec::Vec;
+
+use super::{Any, Custom, ParsingError, SpecificSize, split_size};
+
+/// Registry of [`Custom`] multiples, used to parse sizes.
+///
+/// Next to the registered custom multiples, all multiples supported by
+/// [`Any`] can be parsed.
+///
+/// ```
+/// # extern crate human_size;
+/// # fn main() {
+/// use human_size::{Custom, Kibibyte, SpecificSize};
+/// use human_size::registry::UnitRegistry;
+///
+/// let registry = UnitRegistry::new()
+///     .register(Custom::new("block", "blk", 4096.0))
+///     .register(Custom::new("sector", "sec", 512.0));
+///
+/// let size = registry.parse("20 blocks").unwrap();
+/// assert_eq!(size.to_string(), "20 blk");
+/// assert_eq!(size, SpecificSize::new(80, Kibibyte).unwrap());
+///
+/// let size = registry.parse("8 sec").unwrap();
+/// assert_eq!(size, registry.parse("1 blk").unwrap());
+///
+/// // Regular multiples can also be parsed.
+/// let size = registry.parse("10 KiB").unwrap();
+/// assert_eq!(size.multiple().symbol(), "KiB");
+/// # }
+/// ```
+///
+/// [`Custom`]: ../multiples/struct.Custom.html
+/// [`Any`]: ../multiples/enum.Any.html
+#[derive(Clone, Debug, Default)]
+pub struct UnitRegistry {
+    units: Vec<Custom>,
+}
+
+impl UnitRegistry {
+    /// Create a new, empty registry.
+    pub fn new() -> UnitRegistry {
+        UnitRegistry { units: Vec::new() }
+    }
+
+    /// Register a custom multiple.
+    ///
+    /// Later registered multiples take precedence over earlier registered
+    /// multiples and over the multiples supported by [`Any`].
+    ///
+    /// # Multiples defined at runtime
+    ///
+    /// [`Custom`] only holds `&'static str`s, so that it can be `Copy` and
+    /// used in constants. This means the name and symbol of multiples loaded
+    /// at runtime, e.g. from a configuration file, must be leaked, e.g. using
+    /// `String::leak`. As multiples are usually registered once this only
+    /// leaks a small, fixed amount of memory, but don't do this for every
+    /// parsed size.
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use human_size::Custom;
+    /// use human_size::registry::UnitRegistry;
+    ///
+    /// // E.g. read from a configuration file.
+    /// let config = String::from("page=16384");
+    ///
+    /// let (name, bytes) = config.split_once('=').unwrap();
+    /// let name: &'static str = name.to_owned().leak();
+    /// let page = Custom::new(name, name, bytes.parse().unwrap());
+    /// let registry = UnitRegistry::new().register(page);
+    ///
+    /// let size = registry.parse("2 pages").unwrap();
+    /// assert_eq!(size.to_string(), "2 page");
+    /// # }
+    /// ```
+    ///
+    /// [`Any`]: ../multiples/enum.Any.html
+    /// [`Custom`]: ../multiples/struct.Custom.html
+    pub fn register(mut self, unit: Custom) -> UnitRegistry {
+        self.units.push(unit);
+        self
+    }
+
+    /// Returns all registered custom multiples.
+    pub fn units(&self) -> &[Custom] {
+        &self.units
+    }
+
+    /// Find the multiple for `input`.
+    ///
+    /// Custom multiples are matched on their symbol, name or plural name
+    /// (name followed by "s"), ignoring case.
+    pub fn get(&self, input: &str) -> Option<Custom> {
+        let input = input.trim();
+        self.units
+            .iter()
+            .rev()
+            .find(|unit| {
+                input.eq_ignore_ascii_case(unit.symbol())
+                    || input.eq_ignore_ascii_case(unit.name())
+                    || input
+                        .strip_suffix(['s', 'S'])
+                        .is_some_and(|name| name.eq_ignore_ascii_case(unit.name()))
+            })
+            .copied()
+            .or_else(|| input.parse::<Any>().ok().map(Custom::from))
+    }
+
+    /// Parse a size, e.g. "20 blocks".
+    ///
+    /// This follows the same rules as the [`FromStr`] implementation of
+    /// [`SpecificSize`], with the addition of the registered multiples.
+    ///
+    /// [`FromStr`]: https://doc.rust-lang.org/nightly/core/str/trait.FromStr.html
+    /// [`SpecificSize`]: ../struct.SpecificSize.html
+    pub fn parse(&self, input: &str) -> Result<SpecificSize<Custom>, ParsingError> {
+        let (value, multiple) = split_size(input)?;
+        let multiple = self.get(multiple).ok_or(ParsingError::InvalidMultiple)?;
+        Ok(SpecificSize { value, multiple })
+    }
+}
@@ -0,0 +1,111 @@
+//! Sorting sizes in text, similar to `sort -h`.
+//!
+//! Tokens are parsed using [`parse_lenient`], so "1.5K", "900" and "10 MiB"
+//! are all sizes, and ordered by their number of bytes. Tokens that can't be
+//! parsed are ordered before all sizes.
+//!
+//! ```
+//! # extern crate human_size;
+//! # fn main() {
+//! use human_size::UnitSystem;
+//! use human_size::sort::{SortKey, compare};
+//!
+//! let mut tokens = vec!["2G", "1.5K", "abc", "10 MiB", "900"];
+//! tokens.sort_by(|a, b| compare(a, b, UnitSystem::Binary));
+//! assert_eq!(tokens, ["abc", "900", "1.5K", "10 MiB", "2G"]);
+//!
+//! // Or using a key, parsing each token only once.
+//! let mut tokens = vec!["2G", "1.5K", "abc", "10 MiB", "900"];
+//! tokens.sort_by_cached_key(|token| SortKey::new(token, UnitSystem::Binary));
+//! assert_eq!(tokens, ["abc", "900", "1.5K", "10 MiB", "2G"]);
+//! # }
+//! ```
+//!
+//! [`parse_lenient`]: ../numfmt/fn.parse_lenient.html
+
+use std::cmp::Ordering;
+
+use super::numfmt::parse_lenient;
+use super::{OrdSize, Size, UnitSystem};
+
+/// Compare tokens `a` and `b` as sizes.
+///
+/// Single letter multiples, e.g. "K", are interpreted using `system`. See
+/// the [module documentation] for more.
+///
+/// [module documentation]: index.html
+pub fn compare(a: &str, b: &str, system: UnitSystem) -> Ordering {
+    cmp(
+        (parse_lenient(a, system).ok(), a),
+        (parse_lenient(b, system).ok(), b),
+    )
+}
+
+/// Key to sort tokens as sizes.
+///
+/// Tokens that are sizes are ordered by their exact number of bytes, see
+/// [`OrdSize`]. Tokens that can't be parsed are ordered before all sizes, in
+/// lexicographical order. Tokens of equal size are also ordered
+/// lexicographically, e.g. "1000K" before "1M", to make the order total.
+///
+/// [`OrdSize`]: ../struct.OrdSize.html
+#[derive(Clone, Debug)]
+pub struct SortKey {
+    size: Option<Size>,
+    token: String,
+}
+
+impl SortKey {
+    /// Create a new key for `token`.
+    ///
+    /// Single letter multiples, e.g. "K", are interpreted using `system`.
+    pub fn new(token: &str, system: UnitSystem) -> SortKey {
+        SortKey {
+            size: parse_lenient(token, system).ok(),
+            token: token.to_owned(),
+        }
+    }
+
+    /// Returns the parsed size, or `None` if the token can't be parsed.
+    pub fn size(&self) -> Option<Size> {
+        self.size
+    }
+
+    /// Returns the token.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+}
+
+impl PartialEq for SortKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SortKey {}
+
+impl PartialOrd for SortKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp((self.size, &self.token), (other.size, &other.token))
+    }
+}
+
+/// Compare two tokens, with `None` as size for tokens that can't be parsed.
+fn cmp(a: (Option<Size>, &str), b: (Option<Size>, &str)) -> Ordering {
+    let size = match (a.0, b.0) {
+        // Not using the `PartialOrd` implementation of `Size`, as it allows
+        // for rounding errors, which makes it intransitive.
+        (Some(a), Some(b)) => OrdSize::new(a).cmp(&OrdSize::new(b)),
+        (Some(_), None) => Ordering::Greater,
+        (None, Some(_)) => Ordering::Less,
+        (None, None) => Ordering::Equal,
+    };
+    size.then_with(|| a.1.cmp(b.1))
+}
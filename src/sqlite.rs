@@ -0,0 +1,131 @@
+//! Storing sizes in SQLite using [`rusqlite`].
+//!
+//! By default a [`SpecificSize`] is stored as an `INTEGER` column holding the
+//! number of bytes. This makes it possible to compare and aggregate sizes in
+//! SQL, e.g. `SUM(size)`. Reading a size accepts `INTEGER`, `REAL` (both in
+//! bytes) and `TEXT` (parsed using the [`FromStr`] implementation) columns.
+//!
+//! To store the size in the human readable form, i.e. `TEXT` column holding
+//! the `Display` form, wrap the size in [`Text`].
+//!
+//! ```
+//! # extern crate human_size;
+//! # extern crate rusqlite;
+//! # fn main() -> rusqlite::Result<()> {
+//! use human_size::{SpecificSize, Kilobyte, Mebibyte};
+//! use human_size::sqlite::Text;
+//! use rusqlite::Connection;
+//!
+//! let conn = Connection::open_in_memory()?;
+//! conn.execute("CREATE TABLE files (size INTEGER, human TEXT)", ())?;
+//!
+//! let size = SpecificSize::new(2, Mebibyte).unwrap();
+//! conn.execute("INSERT INTO files VALUES (?1, ?2)", (size, Text(size)))?;
+//!
+//! let bytes: i64 = conn.query_row("SELECT size FROM files", (), |row| row.get(0))?;
+//! assert_eq!(bytes, 2097152);
+//! let human: String = conn.query_row("SELECT human FROM files", (), |row| row.get(0))?;
+//! assert_eq!(human, "2 MiB");
+//!
+//! // Sizes can be read in any multiple.
+//! let size: SpecificSize<Kilobyte> = conn.query_row("SELECT size FROM files", (), |row| row.get(0))?;
+//! assert_eq!(size.to_string(), "2097.152 kB");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`rusqlite`]: https://docs.rs/rusqlite
+//! [`SpecificSize`]: ../struct.SpecificSize.html
+//! [`FromStr`]: https://doc.rust-lang.org/nightly/core/str/trait.FromStr.html
+//! [`Text`]: struct.Text.html
+
+use std::error::Error;
+use std::fmt;
+
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+use super::{Any, InvalidValueError, Multiple, SpecificSize, is_valid_value};
+
+/// Wrapper around [`SpecificSize`] to store it as `TEXT`, using the `Display`
+/// implementation, rather then `INTEGER` bytes.
+///
+/// [`SpecificSize`]: ../struct.SpecificSize.html
+#[derive(Copy, Clone, Debug)]
+pub struct Text<M = Any>(pub SpecificSize<M>);
+
+impl<M> ToSql for Text<M>
+where
+    M: Multiple + Copy + fmt::Display,
+{
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.0.to_string()))
+    }
+}
+
+impl<M> FromSql for Text<M>
+where
+    M: Multiple + Copy,
+{
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        SpecificSize::column_result(value).map(Text)
+    }
+}
+
+impl<M> ToSql for SpecificSize<M>
+where
+    M: Multiple + Copy,
+{
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        // Rounding errors are ignored, e.g. "32.3 kB" is stored as 32300, and
+        // negative sizes are stored as a negative number of bytes.
+        let abs = SpecificSize {
+            value: self.value.abs(),
+            multiple: self.multiple,
+        };
+        let whole = abs
+            .whole_bytes_of(None)
+            .ok()
+            .and_then(|whole| i128::try_from(whole).ok())
+            .map(|whole| if self.value < 0.0 { -whole } else { whole })
+            .and_then(|whole| i64::try_from(whole).ok());
+        match whole {
+            Some(bytes) => Ok(ToSqlOutput::from(bytes)),
+            None => Err(rusqlite::Error::ToSqlConversionFailure(Box::new(
+                IntegerConversionError,
+            ))),
+        }
+    }
+}
+
+impl<M> FromSql for SpecificSize<M>
+where
+    M: Multiple,
+{
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            // Every `i64` is a valid value.
+            ValueRef::Integer(bytes) => Ok(M::from_any(bytes as f64, Any::Byte)),
+            ValueRef::Real(bytes) if is_valid_value(bytes) => Ok(M::from_any(bytes, Any::Byte)),
+            ValueRef::Real(_) => Err(FromSqlError::other(InvalidValueError)),
+            ValueRef::Text(_) => value.as_str()?.parse().map_err(FromSqlError::other),
+            ValueRef::Null | ValueRef::Blob(_) => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+/// The error returned when trying to store a [`SpecificSize`] as `INTEGER`
+/// that is not a whole number of bytes or doesn't fit in an `INTEGER`. Use
+/// [`Text`] to store such sizes.
+///
+/// [`SpecificSize`]: ../struct.SpecificSize.html
+/// [`Text`]: struct.Text.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct IntegerConversionError;
+
+impl fmt::Display for IntegerConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad("size is not a whole number of bytes that fits in an INTEGER")
+    }
+}
+
+impl Error for IntegerConversionError {}
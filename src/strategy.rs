@@ -0,0 +1,143 @@
+//! [`proptest`] strategies for sizes.
+//!
+//! All generated sizes have a value that is valid (see [`SpecificSize::new`])
+//! and not negative, which means that they can be displayed and parsed again.
+//!
+//! ```
+//! # extern crate human_size;
+//! # extern crate proptest;
+//! use human_size::Size;
+//! use human_size::strategy::size_string;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn parse_size(input in size_string()) {
+//!         let size: Size = input.parse().unwrap();
+//!         prop_assert!(size.value() >= 0.0);
+//!     }
+//! }
+//! # fn main() { parse_size(); }
+//! ```
+//!
+//! [`proptest`]: https://docs.rs/proptest
+//! [`SpecificSize::new`]: ../struct.SpecificSize.html#method.new
+
+use std::fmt;
+
+use proptest::prelude::*;
+use proptest::sample::select;
+
+use super::{Any, Multiple, SpecificSize, from_value};
+
+/// Strategy to generate a [`SpecificSize`] with a valid, not negative value.
+///
+/// For `SpecificSize<Any>` the multiple is picked at random.
+///
+/// [`SpecificSize`]: ../struct.SpecificSize.html
+pub fn size<M>() -> impl Strategy<Value = SpecificSize<M>>
+where
+    M: Multiple + fmt::Debug,
+{
+    (value(), multiple()).prop_map(|(value, multiple)| from_value(value, multiple))
+}
+
+/// Strategy to generate any multiple.
+pub fn multiple() -> impl Strategy<Value = Any> {
+    select(Any::all())
+}
+
+/// Strategy to generate a valid, not negative value for a size.
+pub fn value() -> impl Strategy<Value = f64> {
+    use proptest::num::f64::{NORMAL, POSITIVE, ZERO};
+    prop_oneof![
+        Just(0.0),
+        // Most sizes are somewhat small whole numbers.
+        (0..100_000_u32).prop_map(f64::from),
+        0.0..10_000.0,
+        POSITIVE | NORMAL | ZERO,
+    ]
+}
+
+/// Strategy to generate a string that can be parsed into a size.
+///
+/// This covers all spellings of the multiples, including different casing,
+/// different amounts of whitespace (including none) and values formatted
+/// with and without precision.
+pub fn size_string() -> impl Strategy<Value = String> {
+    (
+        value_string(),
+        unit_string(),
+        whitespace(),
+        whitespace(),
+        whitespace(),
+    )
+        .prop_map(|(value, unit, leading, middle, trailing)| {
+            format!("{}{}{}{}{}", leading, value, middle, unit, trailing)
+        })
+}
+
+/// Strategy to generate the value part of a size string.
+fn value_string() -> impl Strategy<Value = String> {
+    (value(), proptest::option::of(0..10_usize), any::<bool>()).prop_map(
+        |(value, precision, trim)| {
+            let value = match precision {
+                Some(precision) => format!("{:.*}", precision, value),
+                None => format!("{}", value),
+            };
+            match (trim, value.strip_prefix('0')) {
+                // ".5" is also valid.
+                (true, Some(fraction)) if fraction.starts_with('.') => fraction.to_owned(),
+                // And so is "5.".
+                (true, _) if !value.contains('.') => value + ".",
+                _ => value,
+            }
+        },
+    )
+}
+
+/// Strategy to generate the multiple part of a size string, in all possible
+/// casings.
+fn unit_string() -> impl Strategy<Value = String> {
+    let unit = multiple().prop_map(Any::symbol);
+    prop_oneof![
+        Just("KB".to_owned()),
+        (unit, any::<u8>()).prop_map(|(name, casing)| {
+            name.chars()
+                .enumerate()
+                .map(|(i, c)| {
+                    if casing & (1 << i) == 0 {
+                        c.to_ascii_lowercase()
+                    } else {
+                        c.to_ascii_uppercase()
+                    }
+                })
+                .collect()
+        }),
+    ]
+}
+
+/// Strategy to generate whitespace, including none.
+fn whitespace() -> impl Strategy<Value = String> {
+    prop_oneof![Just(""), Just(" "), Just("  "), Just("\t"), Just(" \n ")].prop_map(String::from)
+}
+
+impl<M> Arbitrary for SpecificSize<M>
+where
+    M: Multiple + fmt::Debug + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<SpecificSize<M>>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        size().boxed()
+    }
+}
+
+impl Arbitrary for Any {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Any>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        multiple().boxed()
+    }
+}
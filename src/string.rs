@@ -0,0 +1,199 @@
+//! Formatting sizes without allocating.
+
+use core::{fmt, ops, str};
+
+use super::SpecificSize;
+
+/// Fixed capacity string holding a formatted size, see
+/// [`SpecificSize::to_size_string`].
+///
+/// ```
+/// # extern crate human_size;
+/// # fn main() {
+/// use human_size::{SpecificSize, Mebibyte};
+///
+/// let size = SpecificSize::new(1.5, Mebibyte).unwrap();
+/// let string = size.to_size_string();
+/// assert_eq!(string, "1.5 MiB");
+/// assert_eq!(string.len(), 7);
+/// # }
+/// ```
+///
+/// [`SpecificSize::to_size_string`]: struct.SpecificSize.html#method.to_size_string
+#[derive(Clone)]
+pub struct SizeString {
+    buf: [u8; SizeString::CAPACITY],
+    len: usize,
+}
+
+impl SizeString {
+    /// Maximum length of the string in bytes.
+    ///
+    /// This is enough to hold every size formatted using its default
+    /// `Display` implementation, i.e. `{}`, for all multiples, except for
+    /// [`Custom`] multiples with a symbol longer than three bytes. It's made
+    /// up of:
+    ///
+    ///  * 327 bytes for the longest value, `-2.2250738585072014e-308`
+    ///    formatted without exponent: a sign, "0.", 307 zeros and 17
+    ///    significant digits;
+    ///  * 1 byte for the space between the value and the multiple;
+    ///  * 3 bytes for the longest symbol, e.g. "KiB".
+    ///
+    /// Formatting with a precision or width, e.g. `{:.400}`, can require
+    /// more space, writing such a size returns an error.
+    ///
+    /// [`Custom`]: multiples/struct.Custom.html
+    pub const CAPACITY: usize = 327 + 1 + 3;
+
+    /// Create a new, empty string.
+    pub const fn new() -> SizeString {
+        SizeString {
+            buf: [0; SizeString::CAPACITY],
+            len: 0,
+        }
+    }
+
+    /// Returns the string.
+    pub fn as_str(&self) -> &str {
+        // Only complete strings are written into `buf`, so this is valid
+        // UTF-8.
+        str::from_utf8(&self.buf[..self.len]).expect("invalid UTF-8 in SizeString")
+    }
+
+    /// Returns the string as bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+impl Default for SizeString {
+    fn default() -> SizeString {
+        SizeString::new()
+    }
+}
+
+/// Returns an error if the string doesn't fit. A single `write_str` call
+/// either writes the entire string or nothing, but after an error formatting
+/// a value (using multiple calls) the contents of the string are unspecified.
+impl fmt::Write for SizeString {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > SizeString::CAPACITY {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl ops::Deref for SizeString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for SizeString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for SizeString {
+    fn eq(&self, other: &SizeString) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl Eq for SizeString {}
+
+impl PartialEq<str> for SizeString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for SizeString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl fmt::Debug for SizeString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl fmt::Display for SizeString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl<M: fmt::Display> SpecificSize<M> {
+    /// Format the size into `writer`, same as using its `Display`
+    /// implementation, without allocating.
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() {
+    /// use std::fmt::Write;
+    ///
+    /// use human_size::{SpecificSize, Kilobyte};
+    ///
+    /// let size = SpecificSize::new(10, Kilobyte).unwrap();
+    /// let mut output = String::from("size: ");
+    /// size.format_into(&mut output).unwrap();
+    /// assert_eq!(output, "size: 10 kB");
+    /// # }
+    /// ```
+    pub fn format_into<W: fmt::Write>(&self, writer: &mut W) -> fmt::Result {
+        write!(writer, "{}", self)
+    }
+
+    /// Format the size into a [`SizeString`], same as `to_string`, but
+    /// without allocating.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the formatted size is longer than
+    /// [`SizeString::CAPACITY`], which is only possible for [`Custom`]
+    /// multiples with symbols longer than three bytes (or other multiples
+    /// that format as more than three bytes). Use [`format_into`] to handle
+    /// this, or to format using a precision or width, e.g. `{:.2}`.
+    ///
+    /// [`SizeString`]: struct.SizeString.html
+    /// [`SizeString::CAPACITY`]: struct.SizeString.html#associatedconstant.CAPACITY
+    /// [`Custom`]: multiples/struct.Custom.html
+    /// [`format_into`]: struct.SpecificSize.html#method.format_into
+    pub fn to_size_string(&self) -> SizeString {
+        let mut string = SizeString::new();
+        self.format_into(&mut string)
+            .expect("formatted size too long for SizeString");
+        string
+    }
+
+    /// Write the formatted size into `writer`, same as using its `Display`
+    /// implementation, without allocating.
+    ///
+    /// ```
+    /// # extern crate human_size;
+    /// # fn main() -> std::io::Result<()> {
+    /// use human_size::{SpecificSize, Kilobyte};
+    ///
+    /// let size = SpecificSize::new(10, Kilobyte).unwrap();
+    /// let mut output = Vec::new();
+    /// size.write_to(&mut output)?;
+    /// assert_eq!(output, b"10 kB");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        write!(writer, "{}", self)
+    }
+}
@@ -0,0 +1,85 @@
+//! Support for structured logging using [`valuable`], and through that
+//! [`tracing`].
+//!
+//! A [`SpecificSize`] is represented as a structure with two fields: `human`,
+//! the human readable form (using the `Display` implementation), and `bytes`,
+//! the size in bytes. This allows log backends to display the human readable
+//! form, while still being able to aggregate on the number of bytes. If the
+//! size is a whole number of bytes that fits in an `u64` `bytes` is an `u64`,
+//! otherwise it's an `f64`. Rounding errors are ignored, the same as
+//! [`SpecificSize::try_to_bytes`].
+//!
+//! ```
+//! # extern crate human_size;
+//! # extern crate valuable;
+//! # fn main() {
+//! use human_size::{SpecificSize, Mebibyte};
+//! use valuable::{Valuable, Value};
+//!
+//! let size = SpecificSize::new(1.5, Mebibyte).unwrap();
+//! let Value::Structable(value) = size.as_value() else { unreachable!() };
+//! assert_eq!(value.definition().name(), "SpecificSize");
+//! # }
+//! ```
+//!
+//! # Tracing
+//!
+//! `tracing` can record sizes as structured fields using its support for
+//! `valuable`, which is unstable. To use it enable the `valuable` feature of
+//! both this crate and `tracing`, and build with `--cfg tracing_unstable`,
+//! e.g. by setting `RUSTFLAGS="--cfg tracing_unstable"`.
+//!
+//! ```toml
+//! [dependencies]
+//! human-size = { version = "0.4", features = ["valuable"] }
+//! tracing    = { version = "0.1", features = ["valuable"] }
+//! ```
+//!
+//! After that a size can be recorded as field using `Valuable::as_value`.
+//!
+//! ```ignore
+//! tracing::info!(size = size.as_value(), "downloaded file");
+//! ```
+//!
+//! [`valuable`]: https://docs.rs/valuable
+//! [`tracing`]: https://docs.rs/tracing
+//! [`SpecificSize`]: ../struct.SpecificSize.html
+//! [`SpecificSize::try_to_bytes`]: ../struct.SpecificSize.html#method.try_to_bytes
+
+use std::fmt;
+
+use valuable::{Fields, NamedField, NamedValues, StructDef, Structable, Valuable, Value, Visit};
+
+use super::{Multiple, SpecificSize};
+
+/// Fields of a `SpecificSize`.
+static FIELDS: &[NamedField<'static>] = &[NamedField::new("human"), NamedField::new("bytes")];
+
+impl<M> Valuable for SpecificSize<M>
+where
+    M: Multiple + Copy + fmt::Display,
+{
+    fn as_value(&self) -> Value<'_> {
+        Value::Structable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn Visit) {
+        let human = self.to_string();
+        // Rounding errors are ignored, e.g. "32.3 kB" is recorded as 32300.
+        let bytes = match self.try_to_bytes() {
+            Ok(bytes) => Value::U64(bytes),
+            Err(_) => Value::F64(self.bytes()),
+        };
+        let values = [Value::String(&human), bytes];
+        visit.visit_named_fields(&NamedValues::new(FIELDS, &values));
+    }
+}
+
+impl<M> Structable for SpecificSize<M>
+where
+    M: Multiple + Copy + fmt::Display,
+{
+    fn definition(&self) -> StructDef<'_> {
+        StructDef::new_static("SpecificSize", Fields::Named(FIELDS))
+    }
+}
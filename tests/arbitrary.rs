@@ -0,0 +1,39 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use human_size::{Any, Kilobyte, Size, SpecificSize};
+
+/// Generate a `T` from `data`.
+fn generate<'a, T: Arbitrary<'a>>(data: &'a [u8]) -> T {
+    T::arbitrary(&mut Unstructured::new(data)).unwrap()
+}
+
+#[test]
+fn arbitrary_sizes() {
+    let size: Size = generate(&[]);
+    assert_eq!(size, SpecificSize::new(0, Any::Byte).unwrap());
+
+    let size: Size = generate(&[2, 0, 0, 0, 0, 0, 0, 0xf0, 0x3f]);
+    assert_eq!(size, SpecificSize::new(1, Any::Megabyte).unwrap());
+    assert_eq!(size.multiple(), Any::Megabyte);
+
+    let size: SpecificSize<Kilobyte> = generate(&[2, 0, 0, 0, 0, 0, 0, 0xf0, 0x3f]);
+    assert_eq!(size.to_string(), "1 kB");
+}
+
+#[test]
+fn arbitrary_sizes_are_valid() {
+    // NaN, infinite, negative and subnormal values.
+    let values = [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, -1.0, 1e-310];
+    for value in values {
+        let mut data = vec![0];
+        data.extend_from_slice(&value.to_le_bytes());
+        let size: Size = generate(&data);
+        assert!(
+            SpecificSize::new(size.value(), Any::Byte).is_ok(),
+            "{:?}",
+            size
+        );
+        assert!(size.value() >= 0.0, "{:?}", size);
+    }
+}
@@ -0,0 +1,196 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Run the `human-size` binary with `args` and `stdin`, returning the exit
+/// status, standard out and standard error.
+fn run(args: &[&str], stdin: &str) -> (bool, String, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_human-size"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+/// Create a new CLI test, expecting `$want` on standard out.
+macro_rules! cli_test {
+    ([$($arg:expr),*], $want:expr) => {
+        cli_test!([$($arg),*], "", $want);
+    };
+    ([$($arg:expr),*], $stdin:expr, $want:expr) => {
+        let args = [$($arg),*];
+        let (success, stdout, stderr) = run(&args, $stdin);
+        assert!(success, "args: {:?}, stderr: {}", args, stderr);
+        assert_eq!(stdout, $want, "args: {:?}", args);
+    };
+}
+
+/// Create a new CLI test, expecting the command to fail with `$want` on
+/// standard error.
+macro_rules! cli_error_test {
+    ([$($arg:expr),*], $want:expr) => {
+        let args = [$($arg),*];
+        let (success, stdout, stderr) = run(&args, "");
+        assert!(!success, "args: {:?}, stdout: {}", args, stdout);
+        assert_eq!(stderr, $want, "args: {:?}", args);
+    };
+}
+
+#[test]
+fn convert() {
+    cli_test!(["convert", "10 GiB", "--to", "MB"], "10737.41824 MB\n");
+    cli_test!(["convert", "1.5kB", "--to", "B"], "1500 B\n");
+    cli_test!(["convert", "32.3 kB", "--to", "B"], "32300 B\n");
+    cli_test!(["convert", "1 MiB", "--to", "KiB"], "1024 KiB\n");
+    cli_test!(
+        ["convert", "1 GB", "--to", "GiB", "--precision", "2"],
+        "0.93 GiB\n"
+    );
+}
+
+#[test]
+fn humanize() {
+    cli_test!(["humanize", "1572864"], "1.572864 MB\n");
+    cli_test!(["humanize", "--binary", "1572864"], "1.5 MiB\n");
+    cli_test!(
+        ["humanize", "100", "1000", "1500000"],
+        "100 B\n1 kB\n1.5 MB\n"
+    );
+    cli_test!(["humanize", "--binary"], "1024\n\n2048\n", "1 KiB\n2 KiB\n");
+}
+
+#[test]
+fn compare() {
+    cli_test!(["compare", "10 GiB", "10 GB"], "10 GiB > 10 GB\n");
+    cli_test!(["compare", "1 kB", "1000 B"], "1 kB = 1000 B\n");
+    cli_test!(["compare", "1 kB", "1 KiB"], "1 kB < 1 KiB\n");
+}
+
+#[test]
+fn sum() {
+    cli_test!(["sum", "1 kB", "500 B"], "1.5 kB\n");
+    cli_test!(["sum", "--binary", "1 MiB", "512 KiB"], "1.5 MiB\n");
+    cli_test!(["sum", "--to", "B", "1 kB", "1 KiB"], "2024 B\n");
+    cli_test!(["sum", "--to", "B", "32.3 kB", "1 kB"], "33300 B\n");
+    cli_test!(["sum"], "1 MB\n1 MB\n", "2 MB\n");
+}
+
+#[test]
+fn sort() {
+    cli_test!(
+        ["sort"],
+        "2G\n1.5K\nabc\n10 MiB\n900\n",
+        "abc\n900\n1.5K\n10 MiB\n2G\n"
+    );
+    cli_test!(["sort", "--reverse", "1K", "1M", "1G"], "1G\n1M\n1K\n");
+    cli_test!(["sort", "--binary", "1000K", "1M"], "1000K\n1M\n");
+    cli_test!(
+        ["sort", "--field", "2"],
+        "a.txt 1.5M\nb.txt 10K\nc.txt\n",
+        "c.txt\nb.txt 10K\na.txt 1.5M\n"
+    );
+    cli_test!(["sort", "--json", "1M", "1K"], "[\"1K\",\"1M\"]\n");
+}
+
+#[test]
+fn numfmt() {
+    cli_test!(
+        ["numfmt", "--field", "2"],
+        "a 1000\nb 1500000\n",
+        "a 1 kB\nb 1.5 MB\n"
+    );
+    cli_test!(
+        ["numfmt", "--binary", "--field", "2", "--preserve-width"],
+        "a.txt 1024\nb.txt    2048\n",
+        "a.txt 1 KiB\nb.txt   2 KiB\n"
+    );
+    cli_test!(
+        [
+            "numfmt",
+            "--from-human",
+            "-d",
+            ",",
+            "--field",
+            "2",
+            "--field",
+            "3"
+        ],
+        "x,1.5G,3K\n",
+        "x,1500000000,3000\n"
+    );
+    cli_test!(
+        [
+            "numfmt",
+            "--from-human",
+            "--binary",
+            "--regex",
+            "size=(\\S+)"
+        ],
+        "size=1K other=1K\n",
+        "size=1024 other=1K\n"
+    );
+    cli_test!(["numfmt", "--padding", "-8"], "1000 x\n", "1 kB     x\n");
+}
+
+#[test]
+fn json_output() {
+    cli_test!(
+        ["convert", "--json", "1.5 kB", "--to", "B"],
+        "{\"bytes\":1500,\"human\":\"1500 B\",\"multiple\":\"B\",\"value\":1500.0}\n"
+    );
+    // 32.3 kB is 32299.999999999996 bytes as `f64`.
+    cli_test!(
+        ["convert", "--json", "32.3 kB", "--to", "B"],
+        "{\"bytes\":32300,\"human\":\"32300 B\",\"multiple\":\"B\",\"value\":32300.0}\n"
+    );
+    cli_test!(
+        ["convert", "--json", "32.3 kB", "--to", "kB"],
+        "{\"bytes\":32300,\"human\":\"32.3 kB\",\"multiple\":\"kB\",\"value\":32.3}\n"
+    );
+    cli_test!(
+        ["compare", "--json", "1 kB", "1 KiB"],
+        concat!(
+            "{\"left\":{\"bytes\":1000,\"human\":\"1 kB\",\"multiple\":\"kB\",\"value\":1.0},",
+            "\"ordering\":\"less\",",
+            "\"right\":{\"bytes\":1024,\"human\":\"1 KiB\",\"multiple\":\"KiB\",\"value\":1.0}}\n"
+        )
+    );
+    cli_test!(
+        ["sum", "--json", "1 kB", "0.5 B"],
+        "{\"count\":2,\"total\":{\"bytes\":1000.5,\"human\":\"1.0005 kB\",\"multiple\":\"kB\",\"value\":1.0005}}\n"
+    );
+
+    let (success, stdout, _) = run(&["convert", "--json", "10 abc", "--to", "B"], "");
+    assert!(!success);
+    assert_eq!(stdout, "{\"error\":\"invalid multiple: '10 abc'\"}\n");
+}
+
+#[test]
+fn errors() {
+    cli_error_test!(
+        ["convert", "10 abc", "--to", "B"],
+        "human-size: invalid multiple: '10 abc'\n"
+    );
+    cli_error_test!(
+        ["convert", "10 B", "--to", "abc"],
+        "human-size: invalid multiple: 'abc'\n"
+    );
+    cli_error_test!(["humanize", "abc"], "human-size: invalid value: 'abc'\n");
+    cli_error_test!(["compare", "", "1 B"], "human-size: input is empty: ''\n");
+    cli_error_test!(["sum", "1 B", "10"], "human-size: no multiple: '10'\n");
+}
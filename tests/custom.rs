@@ -0,0 +1,151 @@
+use human_size::{Any, Byte, Custom, Kibibyte, Kilobyte, Size, SpecificSize};
+#[cfg(feature = "alloc")]
+use human_size::{ParsingError, registry::UnitRegistry};
+
+const BLOCK: Custom = Custom::new("block", "blk", 4096.0);
+const SECTOR: Custom = Custom::new("sector", "sectors", 512.0);
+
+#[test]
+fn custom() {
+    assert_eq!(BLOCK.name(), "block");
+    assert_eq!(BLOCK.symbol(), "blk");
+    assert_eq!(BLOCK.bytes(), 4096.0);
+    assert_eq!(BLOCK.to_string(), "blk");
+    assert_eq!(format!("{:>5}", BLOCK), "  blk");
+
+    let kilobyte = Custom::from(Any::Kilobyte);
+    assert_eq!(kilobyte, Custom::new("kilobyte", "kB", 1000.0));
+    assert_eq!(Custom::from(Any::Gigibyte).name(), "gibibyte");
+}
+
+#[test]
+#[should_panic(expected = "bytes must be a positive, finite number")]
+fn custom_invalid_bytes() {
+    let _ = Custom::new("nothing", "n", 0.0);
+}
+
+#[test]
+fn conversions() {
+    let size = SpecificSize::new(20, BLOCK).unwrap();
+    assert_eq!(size.to_string(), "20 blk");
+    assert_eq!(format!("{:.1}", size), "20.0 blk");
+    assert_eq!(size.to_multiple(Any::Kibibyte).to_string(), "80 KiB");
+    assert_eq!(size.to_multiple(SECTOR).to_string(), "160 sectors");
+    assert_eq!(size.into::<Byte>().to_string(), "81920 B");
+    assert_eq!(size.to_bytes(), 81920);
+
+    let size = SpecificSize::new(1, Kilobyte).unwrap();
+    assert_eq!(size.to_multiple(SECTOR).value(), 1000.0 / 512.0);
+    // Converting by type keeps the multiple.
+    let size: SpecificSize<Custom> = size.into();
+    assert_eq!(size.to_string(), "1 kB");
+    let size: SpecificSize<Custom> = "1.5 MiB".parse().unwrap();
+    assert_eq!(size.to_string(), "1.5 MiB");
+}
+
+#[test]
+fn comparison() {
+    let blocks = SpecificSize::new(2, BLOCK).unwrap();
+    let sectors = SpecificSize::new(16, SECTOR).unwrap();
+    assert_eq!(blocks, sectors);
+    assert_eq!(blocks, SpecificSize::new(8, Kibibyte).unwrap());
+    assert_eq!(blocks, "8 KiB".parse::<Size>().unwrap());
+    assert!(blocks > SpecificSize::new(8, Kilobyte).unwrap());
+    assert!(SpecificSize::new(1, SECTOR).unwrap() < SpecificSize::new(1, BLOCK).unwrap());
+}
+
+#[test]
+fn arithmetic() {
+    let blocks = SpecificSize::new(2, BLOCK).unwrap();
+    let sum = blocks + SpecificSize::new(8, SECTOR).unwrap();
+    assert_eq!(sum.to_string(), "3 blk");
+    let diff = blocks - SpecificSize::new(2, Kibibyte).unwrap();
+    assert_eq!(diff.to_string(), "1.5 blk");
+    let sum = SpecificSize::new(1, Kibibyte).unwrap() + blocks;
+    assert_eq!(sum.to_string(), "9 KiB");
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn registry_parse() {
+    let registry = UnitRegistry::new().register(BLOCK).register(SECTOR);
+    assert_eq!(registry.units(), [BLOCK, SECTOR]);
+
+    let tests = [
+        ("20 blk", 20.0, BLOCK),
+        ("20blk", 20.0, BLOCK),
+        (" 1.5 BLK ", 1.5, BLOCK),
+        ("1 block", 1.0, BLOCK),
+        ("2 blocks", 2.0, BLOCK),
+        ("2 Blocks", 2.0, BLOCK),
+        ("8 sectors", 8.0, SECTOR),
+        ("1 sector", 1.0, SECTOR),
+        ("10 KiB", 10.0, Custom::from(Any::Kibibyte)),
+        ("10 kb", 10.0, Custom::from(Any::Kilobyte)),
+        ("100 B", 100.0, Custom::from(Any::Byte)),
+    ];
+    for (input, value, multiple) in tests {
+        let size = registry.parse(input).unwrap();
+        assert_eq!(size.value(), value, "input: {:?}", input);
+        assert_eq!(size.multiple(), multiple, "input: {:?}", input);
+    }
+
+    let tests = [
+        ("", ParsingError::EmptyInput),
+        ("blk", ParsingError::MissingValue),
+        ("10", ParsingError::MissingMultiple),
+        ("1.2.3 blk", ParsingError::InvalidValue),
+        ("10 pages", ParsingError::InvalidMultiple),
+        ("10 blks", ParsingError::InvalidMultiple),
+    ];
+    for (input, want) in tests {
+        assert_eq!(registry.parse(input), Err(want), "input: {:?}", input);
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn registry_parse_same_as_from_str() {
+    let registry = UnitRegistry::new();
+    let tests = [
+        "1.5 kB",
+        " 10MiB ",
+        "0.1 B",
+        ".5 kB",
+        "5. kB",
+        "123456789012345678901 B",
+        "0.000000000000000000000001 YB",
+        "1e3 B",
+        "1..5 B",
+        "\u{0663} B",
+        "10 \u{00B5}B",
+        "  ",
+        "kB",
+        "10",
+    ];
+    for input in tests {
+        let want = input
+            .parse::<Size>()
+            .map(|size| (size.value(), size.multiple().symbol()));
+        let got = registry
+            .parse(input)
+            .map(|size| (size.value(), size.multiple().symbol()));
+        assert_eq!(got, want, "input: {:?}", input);
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn registry_precedence() {
+    // Later registered multiples take precedence, including over `Any`.
+    let page = Custom::new("page", "B", 4096.0);
+    let registry = UnitRegistry::new()
+        .register(Custom::new("page", "pg", 16384.0))
+        .register(page);
+    assert_eq!(registry.get("pages"), Some(page));
+    assert_eq!(registry.get("b"), Some(page));
+    assert_eq!(registry.get("pg").unwrap().bytes(), 16384.0);
+    assert_eq!(registry.get("kB"), Some(Custom::from(Any::Kilobyte)));
+    assert_eq!(registry.get("nothing"), None);
+    assert_eq!(UnitRegistry::new().get("blk"), None);
+}
@@ -0,0 +1,156 @@
+#![cfg(feature = "std")]
+
+use std::env::{self, VarError};
+use std::error::Error;
+use std::ffi::OsString;
+use std::process::Command;
+
+use human_size::env::{EnvSizeError, from_env, from_env_in, from_env_or, from_env_with};
+use human_size::{Any, Byte, Kilobyte, Mebibyte, ParsingError, Size, SpecificSize};
+
+/// Returns a lookup function for `from_env_with` using `vars`.
+fn lookup<'a>(vars: &'a [(&str, &str)]) -> impl Fn(&str) -> Result<String, VarError> + 'a {
+    move |name| {
+        vars.iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, value)| value.to_string())
+            .ok_or(VarError::NotPresent)
+    }
+}
+
+/// Runs test `name` in a child process with the environment variables `vars`
+/// set, returns `true` in the child process, in which the test should run.
+///
+/// Setting environment variables in the test process is not safe, as other
+/// test threads can read the environment at the same time.
+fn in_child(name: &str, vars: &[(&str, &str)]) -> bool {
+    if env::var_os("HUMAN_SIZE_TEST_CHILD").is_some() {
+        return true;
+    }
+    let output = Command::new(env::current_exe().unwrap())
+        .args([name, "--exact", "--test-threads=1"])
+        .env("HUMAN_SIZE_TEST_CHILD", "1")
+        .envs(vars.iter().copied())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "test failed in child process:\n{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    false
+}
+
+#[test]
+fn env() {
+    let vars = [("CACHE_SIZE", "512MiB")];
+    let size: Option<Size> = from_env_with("CACHE_SIZE", lookup(&vars)).unwrap();
+    assert_eq!(size, Some(SpecificSize::new(512, Any::Mebibyte).unwrap()));
+    let size: Option<SpecificSize<Byte>> = from_env_with("CACHE_SIZE", lookup(&vars)).unwrap();
+    assert_eq!(size, Some(SpecificSize::new(536870912, Byte).unwrap()));
+
+    let size: Option<Size> = from_env_with("MISSING", lookup(&vars)).unwrap();
+    assert_eq!(size, None);
+}
+
+#[test]
+fn process_env() {
+    if !in_child("process_env", &[("HUMAN_SIZE_TEST_ENV", "512MiB")]) {
+        return;
+    }
+    let size: Option<Size> = from_env("HUMAN_SIZE_TEST_ENV").unwrap();
+    assert_eq!(size, Some(SpecificSize::new(512, Any::Mebibyte).unwrap()));
+    let size: Option<Size> = from_env("HUMAN_SIZE_TEST_ENV_MISSING").unwrap();
+    assert_eq!(size, None);
+}
+
+#[test]
+fn env_or() {
+    let vars = [
+        ("HUMAN_SIZE_TEST_ENV_OR", " 1 MiB "),
+        ("HUMAN_SIZE_TEST_ENV_OR_INVALID", ""),
+    ];
+    if !in_child("env_or", &vars) {
+        return;
+    }
+
+    let default = SpecificSize::new(64, Mebibyte).unwrap();
+    let size = from_env_or("HUMAN_SIZE_TEST_ENV_OR", default).unwrap();
+    assert_eq!(size, SpecificSize::new(1, Mebibyte).unwrap());
+    let size = from_env_or("HUMAN_SIZE_TEST_ENV_OR_MISSING", default).unwrap();
+    assert_eq!(size, default);
+
+    let err = from_env_or("HUMAN_SIZE_TEST_ENV_OR_INVALID", default).unwrap_err();
+    assert_eq!(
+        err,
+        EnvSizeError::Parsing {
+            name: "HUMAN_SIZE_TEST_ENV_OR_INVALID".to_owned(),
+            err: ParsingError::EmptyInput,
+        }
+    );
+}
+
+#[test]
+fn env_in() {
+    let vars = [
+        ("HUMAN_SIZE_TEST_ENV_IN", "1 MB"),
+        ("HUMAN_SIZE_TEST_ENV_IN_SMALL", "10 B"),
+    ];
+    if !in_child("env_in", &vars) {
+        return;
+    }
+
+    let min = SpecificSize::new(1, Kilobyte).unwrap();
+    let max = SpecificSize::new(1000, Kilobyte).unwrap();
+    let size = from_env_in("HUMAN_SIZE_TEST_ENV_IN", min..=max).unwrap();
+    assert_eq!(size, Some(max));
+    let err = from_env_in("HUMAN_SIZE_TEST_ENV_IN", min..max).unwrap_err();
+    assert_eq!(
+        err,
+        EnvSizeError::OutOfRange {
+            name: "HUMAN_SIZE_TEST_ENV_IN".to_owned(),
+            size: SpecificSize::new(1000, Any::Kilobyte).unwrap(),
+        }
+    );
+    assert_eq!(err.name(), "HUMAN_SIZE_TEST_ENV_IN");
+    assert_eq!(
+        err.to_string(),
+        "size in environment variable `HUMAN_SIZE_TEST_ENV_IN` is out of range: 1000 kB"
+    );
+    assert!(err.source().is_none());
+
+    let err = from_env_in("HUMAN_SIZE_TEST_ENV_IN_SMALL", min..).unwrap_err();
+    assert_eq!(err.name(), "HUMAN_SIZE_TEST_ENV_IN_SMALL");
+
+    let size = from_env_in("HUMAN_SIZE_TEST_ENV_IN_MISSING", min..max).unwrap();
+    assert_eq!(size, None);
+}
+
+#[test]
+fn errors() {
+    let vars = [("MAX_SIZE", "100")];
+    let err = from_env_with::<Byte, _>("MAX_SIZE", lookup(&vars)).unwrap_err();
+    assert_eq!(err.name(), "MAX_SIZE");
+    assert_eq!(
+        err.to_string(),
+        "invalid size in environment variable `MAX_SIZE`: no multiple"
+    );
+    assert_eq!(err.source().unwrap().to_string(), "no multiple");
+}
+
+#[test]
+fn not_unicode() {
+    let lookup = |_: &str| Err(VarError::NotUnicode(OsString::from("1 MB")));
+    let err = from_env_with::<Byte, _>("MAX_SIZE", lookup).unwrap_err();
+    assert_eq!(
+        err,
+        EnvSizeError::NotUnicode {
+            name: "MAX_SIZE".to_owned()
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "environment variable `MAX_SIZE` is not valid unicode"
+    );
+}
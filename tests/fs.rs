@@ -0,0 +1,215 @@
+#![cfg(feature = "std")]
+
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::{env, process};
+
+use human_size::fs::{MetadataExt, PathExt, SizeKind, Symlinks, Walker};
+use human_size::{Byte, SpecificSize};
+
+/// Temporary directory, removed when dropped.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> TempDir {
+        let path = env::temp_dir().join(format!("human-size-{}-{}", name, process::id()));
+        if path.exists() {
+            fs::remove_dir_all(&path).unwrap();
+        }
+        fs::create_dir_all(&path).unwrap();
+        TempDir(path)
+    }
+
+    fn file(&self, name: &str, size: usize) -> PathBuf {
+        let path = self.0.join(name);
+        File::create(&path)
+            .unwrap()
+            .write_all(&vec![b'a'; size])
+            .unwrap();
+        path
+    }
+
+    fn dir(&self, name: &str) -> PathBuf {
+        let path = self.0.join(name);
+        fs::create_dir(&path).unwrap();
+        path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn bytes(bytes: u64) -> SpecificSize<Byte> {
+    SpecificSize::new(bytes as f64, Byte).unwrap()
+}
+
+fn dir_size(path: &Path) -> u64 {
+    fs::symlink_metadata(path).unwrap().len()
+}
+
+#[test]
+fn file_size() {
+    let dir = TempDir::new("file_size");
+    let path = dir.file("file", 1500);
+    assert_eq!(path.file_size().unwrap(), bytes(1500));
+    assert_eq!(path.file_size().unwrap().to_string(), "1500 B");
+    let metadata = fs::metadata(&path).unwrap();
+    assert_eq!(metadata.file_size(), bytes(1500));
+    // Not checking the exact size as file systems can compress files or store
+    // small files inline.
+    assert!(metadata.allocated_size() > bytes(0));
+
+    let err = dir.0.join("missing").file_size().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+}
+
+#[test]
+fn walk_apparent() {
+    let dir = TempDir::new("walk_apparent");
+    let a = dir.file("a", 100);
+    let sub = dir.dir("sub");
+    let b = dir.file("sub/b", 2000);
+    let c = dir.file("sub/c", 30);
+
+    let usage = Walker::new()
+        .size_kind(SizeKind::Apparent)
+        .walk(&dir.0)
+        .unwrap();
+    let sub_size = dir_size(&sub) + 2030;
+    assert_eq!(usage.total(), bytes(dir_size(&dir.0) + 100 + sub_size));
+
+    let mut entries: Vec<_> = usage
+        .entries()
+        .iter()
+        .map(|entry| (entry.path().to_owned(), entry.size(), entry.is_dir()))
+        .collect();
+    // The walked directory is always last.
+    assert_eq!(entries.pop(), Some((dir.0.clone(), usage.total(), true)));
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    let want = vec![
+        (a, bytes(100), false),
+        (sub, bytes(sub_size), true),
+        (b, bytes(2000), false),
+        (c, bytes(30), false),
+    ];
+    assert_eq!(entries, want);
+}
+
+#[test]
+fn walk_allocated() {
+    let dir = TempDir::new("walk_allocated");
+    let _ = dir.file("a", 100);
+    let _ = dir.file("b", 0);
+    let usage = Walker::new().walk(&dir.0).unwrap();
+    let want: SpecificSize<Byte> = usage
+        .entries()
+        .iter()
+        .map(|entry| fs::symlink_metadata(entry.path()).unwrap().allocated_size())
+        .fold(bytes(0), |total, size| total + size);
+    assert_eq!(usage.total(), want);
+}
+
+#[test]
+fn walk_file() {
+    let dir = TempDir::new("walk_file");
+    let path = dir.file("file", 100);
+    let usage = Walker::new()
+        .size_kind(SizeKind::Apparent)
+        .walk(&path)
+        .unwrap();
+    assert_eq!(usage.total(), bytes(100));
+    assert_eq!(usage.entries().len(), 1);
+}
+
+#[cfg(unix)]
+#[test]
+fn walk_hardlinks() {
+    let dir = TempDir::new("walk_hardlinks");
+    let a = dir.file("a", 1000);
+    fs::hard_link(&a, dir.0.join("b")).unwrap();
+    let walker = Walker::new().size_kind(SizeKind::Apparent);
+
+    let usage = walker.clone().walk(&dir.0).unwrap();
+    assert_eq!(usage.total(), bytes(dir_size(&dir.0) + 1000));
+    assert_eq!(usage.entries().len(), 2);
+
+    let usage = walker.dedupe_hardlinks(false).walk(&dir.0).unwrap();
+    assert_eq!(usage.total(), bytes(dir_size(&dir.0) + 2000));
+    assert_eq!(usage.entries().len(), 3);
+}
+
+#[cfg(unix)]
+#[test]
+fn walk_symlinks() {
+    use std::os::unix::fs::symlink;
+
+    let dir = TempDir::new("walk_symlinks");
+    let target = TempDir::new("walk_symlinks_target");
+    let _ = target.file("file", 1000);
+    let link = dir.0.join("link");
+    symlink(&target.0, &link).unwrap();
+    // Links back to the walked directory, creating a loop.
+    symlink(&dir.0, dir.0.join("loop")).unwrap();
+    // Dangling link.
+    let dangling = dir.0.join("dangling");
+    symlink(dir.0.join("missing"), &dangling).unwrap();
+    let link_size = fs::symlink_metadata(&link).unwrap().len();
+    let loop_size = fs::symlink_metadata(dir.0.join("loop")).unwrap().len();
+    let dangling_size = fs::symlink_metadata(&dangling).unwrap().len();
+    let walker = Walker::new().size_kind(SizeKind::Apparent);
+
+    let usage = walker
+        .clone()
+        .symlinks(Symlinks::Skip)
+        .walk(&dir.0)
+        .unwrap();
+    assert_eq!(usage.total(), bytes(dir_size(&dir.0)));
+    assert_eq!(usage.entries().len(), 1);
+
+    let usage = walker
+        .clone()
+        .symlinks(Symlinks::Count)
+        .walk(&dir.0)
+        .unwrap();
+    let want = dir_size(&dir.0) + link_size + loop_size + dangling_size;
+    assert_eq!(usage.total(), bytes(want));
+    assert_eq!(usage.entries().len(), 4);
+
+    let usage = walker.symlinks(Symlinks::Follow).walk(&dir.0).unwrap();
+    let want = dir_size(&dir.0) + dir_size(&target.0) + 1000 + dangling_size;
+    assert_eq!(usage.total(), bytes(want));
+    // Directory, link (directory), file in link, and the dangling link.
+    assert_eq!(usage.entries().len(), 4);
+}
+
+#[cfg(unix)]
+#[test]
+fn walk_errors() {
+    use std::os::unix::fs::symlink;
+
+    let dir = TempDir::new("walk_errors");
+    let _ = dir.file("file", 1000);
+    // Following a link to itself fails.
+    let link = dir.0.join("loop");
+    symlink(&link, &link).unwrap();
+    let walker = Walker::new()
+        .size_kind(SizeKind::Apparent)
+        .symlinks(Symlinks::Follow);
+
+    assert!(walker.clone().walk(&dir.0).is_err());
+
+    let usage = walker.collect_errors(true).walk(&dir.0).unwrap();
+    assert_eq!(usage.total(), bytes(dir_size(&dir.0) + 1000));
+    assert_eq!(usage.entries().len(), 2);
+    assert_eq!(usage.errors().len(), 1);
+    let err = &usage.errors()[0];
+    assert_eq!(err.path(), link);
+    assert!(
+        err.to_string()
+            .starts_with(&format!("{}: ", link.display()))
+    );
+}
@@ -0,0 +1,218 @@
+#![cfg(feature = "std")]
+
+use std::io::{self, Read, Write};
+use std::time::Duration;
+
+use human_size::io::{
+    CountingReader, CountingWriter, LimitExceededError, LimitedReader, LimitedWriter,
+};
+use human_size::{Any, Byte, Kibibyte, Kilobyte, Size, SpecificSize};
+
+fn bytes(bytes: u64) -> SpecificSize<Byte> {
+    SpecificSize::new(bytes as f64, Byte).unwrap()
+}
+
+#[test]
+fn counting_reader() {
+    let input = [1; 2500];
+    let mut reader = CountingReader::new(&input[..]);
+    let mut buf = [0; 1000];
+    assert_eq!(reader.read(&mut buf).unwrap(), 1000);
+    assert_eq!(reader.progress().bytes(), bytes(1000));
+    assert_eq!(reader.progress().total(), None);
+    assert_eq!(reader.progress().percent(), None);
+    assert_eq!(reader.progress().eta(), None);
+    assert_eq!(reader.progress().to_string(), "1000 B");
+
+    let mut output = Vec::new();
+    assert_eq!(reader.read_to_end(&mut output).unwrap(), 1500);
+    assert_eq!(reader.progress().bytes(), bytes(2500));
+    assert_eq!(format!("{:.2}", reader.progress()), "2.44 KiB");
+    assert_eq!(reader.into_inner().len(), 0);
+}
+
+#[test]
+fn counting_writer() {
+    let mut writer = CountingWriter::new(Vec::new()).total(SpecificSize::new(4, Kilobyte).unwrap());
+    writer.write_all(&[1; 1000]).unwrap();
+    writer.flush().unwrap();
+    let progress = *writer.progress();
+    assert_eq!(progress.bytes(), bytes(1000));
+    assert_eq!(progress.total(), Some(bytes(4000)));
+    assert_eq!(progress.percent(), Some(25.0));
+    assert_eq!(progress.to_string(), "1000 B of 3.90625 KiB (25%)");
+    assert_eq!(format!("{:.1}", progress), "1000.0 B of 3.9 KiB (25%)");
+    assert!(progress.eta().is_some());
+    assert!(progress.elapsed() < Duration::from_secs(60));
+    assert!(progress.throughput() >= bytes(0));
+
+    writer.write_all(&[1; 4000]).unwrap();
+    // Never more than 100%.
+    assert_eq!(writer.progress().percent(), Some(100.0));
+    assert_eq!(writer.progress().eta(), Some(Duration::ZERO));
+    assert_eq!(writer.get_ref().len(), 5000);
+}
+
+#[test]
+fn empty_total() {
+    let reader = CountingReader::new(io::empty()).total(bytes(0));
+    assert_eq!(reader.progress().percent(), Some(100.0));
+    assert_eq!(reader.progress().eta(), None);
+}
+
+#[test]
+fn rounding_errors() {
+    // 32.3 kB is 32299.999999999996 bytes as `f64`.
+    let size = SpecificSize::new(32.3, Kilobyte).unwrap();
+    let mut reports = Vec::new();
+    let mut writer = CountingWriter::new(io::sink())
+        .total(size)
+        .on_progress(size, |progress| reports.push(progress.bytes().to_bytes()));
+    writer.write_all(&[1; 32299]).unwrap();
+    assert_eq!(writer.progress().total(), Some(bytes(32300)));
+    assert!(writer.progress().percent().unwrap() < 100.0);
+    writer.write_all(&[1; 1]).unwrap();
+    assert_eq!(writer.progress().percent(), Some(100.0));
+    writer.write_all(&[1; 32300]).unwrap();
+    assert_eq!(reports, [32300, 64600]);
+}
+
+#[test]
+fn on_progress() {
+    let mut reports = Vec::new();
+    let mut writer = CountingWriter::new(io::sink())
+        .on_progress(SpecificSize::new(1, Kilobyte).unwrap(), |progress| {
+            reports.push(progress.bytes().to_bytes())
+        });
+    writer.write_all(&[1; 999]).unwrap();
+    writer.write_all(&[1; 1]).unwrap();
+    writer.write_all(&[1; 500]).unwrap();
+    // Passing multiple intervals calls the callback once.
+    writer.write_all(&[1; 2000]).unwrap();
+    writer.write_all(&[1; 499]).unwrap();
+    writer.write_all(&[1; 1]).unwrap();
+    assert_eq!(reports, [1000, 3500, 4000]);
+}
+
+#[test]
+fn on_progress_zero_interval() {
+    let mut reports = Vec::new();
+    let input = [1; 10];
+    let mut reader = CountingReader::new(&input[..]).on_progress(bytes(0), |progress| {
+        reports.push(progress.bytes().to_bytes())
+    });
+    let mut buf = [0; 4];
+    while reader.read(&mut buf).unwrap() != 0 {}
+    assert_eq!(reports, [4, 8, 10]);
+}
+
+#[test]
+fn limited_reader() {
+    let limit = SpecificSize::new(1, Kilobyte).unwrap();
+    let input = [1; 1000];
+    let mut reader = LimitedReader::new(&input[..], limit);
+    let mut output = Vec::new();
+    assert_eq!(reader.read_to_end(&mut output).unwrap(), 1000);
+    assert_eq!(reader.bytes_read(), bytes(1000));
+
+    let input = [1; 1500];
+    let mut reader = LimitedReader::new(&input[..], limit);
+    let mut buf = [0; 600];
+    assert_eq!(reader.read(&mut buf).unwrap(), 600);
+    let err = reader.read(&mut buf).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::FileTooLarge);
+    let err = LimitExceededError::from_io(&err).unwrap();
+    assert_eq!(err.limit(), limit);
+    assert_eq!(err.limit().multiple(), Any::Kilobyte);
+    assert_eq!(err.attempted(), bytes(1001));
+    assert_eq!(
+        err.to_string(),
+        "size limit of 1 kB exceeded, attempted 1001 B"
+    );
+    // Nothing more is counted.
+    assert_eq!(reader.bytes_read(), bytes(600));
+}
+
+#[test]
+fn limited_reader_zero() {
+    let mut reader = LimitedReader::new(io::empty(), bytes(0));
+    assert_eq!(reader.read_to_end(&mut Vec::new()).unwrap(), 0);
+    let mut reader = LimitedReader::new(&[1][..], bytes(0));
+    let err = reader.read_to_end(&mut Vec::new()).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::FileTooLarge);
+}
+
+#[test]
+fn limited_writer() {
+    let limit: Size = "2 KiB".parse().unwrap();
+    let mut writer = LimitedWriter::new(Vec::new(), limit);
+    writer.write_all(&[1; 1024]).unwrap();
+    writer.write_all(&[1; 1024]).unwrap();
+    assert_eq!(
+        writer.bytes_written(),
+        SpecificSize::new(2, Kibibyte).unwrap()
+    );
+    writer.write_all(&[]).unwrap();
+
+    let err = writer.write_all(&[1; 10]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::FileTooLarge);
+    let err = LimitExceededError::from_io(&err).unwrap();
+    assert_eq!(err.limit(), limit);
+    assert_eq!(err.attempted(), bytes(2058));
+    // The write is rejected as a whole.
+    assert_eq!(writer.get_ref().len(), 2048);
+    assert_eq!(writer.into_inner().len(), 2048);
+}
+
+#[test]
+fn limit_rounding_errors() {
+    // 32.3 kB is 32299.999999999996 bytes as `f64`.
+    let limit = SpecificSize::new(32.3, Kilobyte).unwrap();
+    let mut writer = LimitedWriter::new(Vec::new(), limit);
+    writer.write_all(&[1; 32300]).unwrap();
+    let err = writer.write_all(&[1; 1]).unwrap_err();
+    let err = LimitExceededError::from_io(&err).unwrap();
+    assert_eq!(
+        err.to_string(),
+        "size limit of 32.3 kB exceeded, attempted 32301 B"
+    );
+
+    let input = [1; 32300];
+    let mut reader = LimitedReader::new(&input[..], limit);
+    assert_eq!(reader.read_to_end(&mut Vec::new()).unwrap(), 32300);
+
+    let limit = SpecificSize::new(4.1, Any::Megabyte).unwrap();
+    let mut writer = LimitedWriter::new(io::sink(), limit);
+    writer.write_all(&vec![1; 4_100_000]).unwrap();
+    assert!(writer.write_all(&[1; 1]).is_err());
+
+    // Fractional bytes are rounded down.
+    let mut writer = LimitedWriter::new(io::sink(), SpecificSize::new(1.5, Byte).unwrap());
+    writer.write_all(&[1; 1]).unwrap();
+    assert!(writer.write_all(&[1; 1]).is_err());
+}
+
+#[test]
+fn negative_limit() {
+    let limit = SpecificSize::new(-1, Kilobyte).unwrap();
+    let mut writer = LimitedWriter::new(Vec::new(), limit);
+    writer.write_all(&[]).unwrap();
+    let err = writer.write_all(&[1; 1]).unwrap_err();
+    let err = LimitExceededError::from_io(&err).unwrap();
+    assert_eq!(
+        err.to_string(),
+        "size limit of -1 kB exceeded, attempted 1 B"
+    );
+
+    let mut reader = LimitedReader::new(&[1][..], limit);
+    let err = reader.read_to_end(&mut Vec::new()).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::FileTooLarge);
+}
+
+#[test]
+fn limit_exceeded_error_from_io() {
+    let err = io::Error::other("other error");
+    assert_eq!(LimitExceededError::from_io(&err), None);
+    let err = io::Error::from(io::ErrorKind::FileTooLarge);
+    assert_eq!(LimitExceededError::from_io(&err), None);
+}
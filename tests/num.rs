@@ -0,0 +1,114 @@
+#![cfg(feature = "num-traits")]
+
+use human_size::{Any, Byte, Kibibyte, Kilobyte, Size, SpecificSize};
+use num_traits::{Bounded, CheckedAdd, CheckedSub, FromPrimitive, ToPrimitive, Zero};
+
+#[test]
+fn zero() {
+    let zero: SpecificSize<Kilobyte> = Zero::zero();
+    assert_eq!(zero.to_string(), "0 kB");
+    assert!(zero.is_zero());
+    let zero: Size = Zero::zero();
+    assert_eq!(zero.to_string(), "0 B");
+    assert!(zero.is_zero());
+
+    assert!(!SpecificSize::new(1, Byte).unwrap().is_zero());
+}
+
+#[test]
+fn bounded() {
+    let max: SpecificSize<Byte> = Bounded::max_value();
+    assert_eq!(max.value(), f64::MAX);
+    let min: SpecificSize<Byte> = Bounded::min_value();
+    assert_eq!(min.value(), f64::MIN);
+
+    let max: SpecificSize<Kibibyte> = Bounded::max_value();
+    assert_eq!(max.value(), f64::MAX / 1024.0);
+    assert_eq!(max.to_u64(), None);
+}
+
+#[test]
+fn checked_ops() {
+    let size1 = SpecificSize::new(1, Any::Kilobyte).unwrap();
+    let size2 = SpecificSize::new(500, Any::Byte).unwrap();
+    assert_eq!(
+        CheckedAdd::checked_add(&size1, &size2).unwrap().to_string(),
+        "1.5 kB"
+    );
+    assert_eq!(
+        CheckedSub::checked_sub(&size1, &size2).unwrap().to_string(),
+        "0.5 kB"
+    );
+
+    let max: Size = Bounded::max_value();
+    assert_eq!(CheckedAdd::checked_add(&max, &max), None);
+}
+
+#[test]
+fn to_primitive() {
+    let size = SpecificSize::new(1.5, Kilobyte).unwrap();
+    assert_eq!(size.to_u64(), Some(1500));
+    assert_eq!(size.to_i64(), Some(1500));
+    assert_eq!(size.to_u128(), Some(1500));
+    assert_eq!(size.to_f64(), Some(1500.0));
+    assert_eq!(size.to_u8(), None);
+
+    let size = SpecificSize::new(1.5, Byte).unwrap();
+    assert_eq!(size.to_u64(), Some(1));
+    let size = SpecificSize::new(-1, Byte).unwrap();
+    assert_eq!(size.to_u64(), None);
+    assert_eq!(size.to_i64(), Some(-1));
+    let size = SpecificSize::new(-1.5, Byte).unwrap();
+    assert_eq!(size.to_i64(), Some(-1));
+    assert_eq!(size.to_i128(), Some(-1));
+    let size = SpecificSize::new(-0.5, Byte).unwrap();
+    assert_eq!(size.to_u64(), Some(0));
+
+    // 32.3 kB is 32299.999999999996 bytes as `f64`, the same as `TryFrom`.
+    let size = SpecificSize::new(32.3, Kilobyte).unwrap();
+    assert_eq!(size.to_u64(), Some(32300));
+    assert_eq!(size.to_u64().map(Ok), Some(u64::try_from(size)));
+    assert_eq!(size.to_i64(), Some(32300));
+    assert_eq!(size.to_u128(), Some(32300));
+    let size = SpecificSize::new(-32.3, Kilobyte).unwrap();
+    assert_eq!(size.to_i64(), Some(-32300));
+    let size = SpecificSize::new(4.1, Any::Megabyte).unwrap();
+    assert_eq!(size.to_u64(), Some(4_100_000));
+    // Whole values are exact, the same as `TryFrom`.
+    let size = SpecificSize::new(3, Any::Yottabyte).unwrap();
+    assert_eq!(size.to_u128(), Some(3_000_000_000_000_000_000_000_000));
+    assert_eq!(size.to_i128(), Some(3_000_000_000_000_000_000_000_000));
+    let size = SpecificSize::new(-3, Any::Yottabyte).unwrap();
+    assert_eq!(size.to_i128(), Some(-3_000_000_000_000_000_000_000_000));
+}
+
+#[test]
+fn from_primitive() {
+    let size = SpecificSize::<Kilobyte>::from_u64(1500).unwrap();
+    assert_eq!(size.to_string(), "1.5 kB");
+    let size = Size::from_i64(-1).unwrap();
+    assert_eq!(size.to_string(), "-1 B");
+    let size = SpecificSize::<Kibibyte>::from_f64(512.0).unwrap();
+    assert_eq!(size.to_string(), "0.5 KiB");
+
+    assert!(Size::from_f64(f64::NAN).is_none());
+    assert!(Size::from_f64(f64::INFINITY).is_none());
+    assert!(Size::from_f64(1e-310).is_none());
+}
+
+/// Generic code only using `num-traits`.
+fn sum<T: Zero + Copy>(values: &[T]) -> T {
+    values.iter().fold(T::zero(), |acc, value| acc + *value)
+}
+
+#[test]
+fn generic_code() {
+    let sizes = [
+        SpecificSize::new(1, Any::Mebibyte).unwrap(),
+        SpecificSize::new(512, Any::Kibibyte).unwrap(),
+        SpecificSize::new(0.5, Any::Mebibyte).unwrap(),
+    ];
+    let got = sum(&sizes);
+    assert_eq!(got, SpecificSize::new(2, Any::Mebibyte).unwrap());
+    assert_eq!(got.to_string(), "2097152 B");
+}
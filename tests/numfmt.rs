@@ -0,0 +1,210 @@
+#![cfg(feature = "std")]
+
+use human_size::numfmt::{Filter, Padding, parse_lenient};
+use human_size::{Any, ParsingError, SpecificSize, UnitSystem};
+
+macro_rules! lenient_test {
+    ($input:expr, $system:expr, $value:expr, $multiple:expr) => {
+        let got = parse_lenient($input, $system);
+        let want = Ok(SpecificSize::new($value, $multiple).unwrap());
+        assert_eq!(got, want, "input: {:?}", $input);
+        assert_eq!(got.unwrap().multiple(), $multiple, "input: {:?}", $input);
+    };
+    ($input:expr, $err:expr) => {
+        let got = parse_lenient($input, UnitSystem::Decimal);
+        assert_eq!(got, Err($err), "input: {:?}", $input);
+    };
+}
+
+#[test]
+fn lenient_parsing() {
+    use UnitSystem::*;
+    lenient_test!("100", Decimal, 100, Any::Byte);
+    lenient_test!(" 1.5 ", Binary, 1.5, Any::Byte);
+    lenient_test!("-100", Decimal, -100, Any::Byte);
+    lenient_test!("+1.5K", Decimal, 1.5, Any::Kilobyte);
+    lenient_test!("1.5k", Decimal, 1.5, Any::Kilobyte);
+    lenient_test!("1.5K", Binary, 1.5, Any::Kibibyte);
+    lenient_test!("10M", Decimal, 10, Any::Megabyte);
+    lenient_test!("10 G", Binary, 10, Any::Gigibyte);
+    lenient_test!("2T", Decimal, 2, Any::Terabyte);
+    lenient_test!("2P", Decimal, 2, Any::Petabyte);
+    lenient_test!("2E", Binary, 2, Any::Exbibyte);
+    lenient_test!("2Z", Decimal, 2, Any::Zettabyte);
+    lenient_test!("2Y", Binary, 2, Any::Yobibyte);
+    lenient_test!("3R", Decimal, 3, Any::Ronnabyte);
+    lenient_test!("4Q", Binary, 4, Any::Quebibyte);
+    lenient_test!("1.5K", Jedec, 1.5, Any::Kibibyte);
+    lenient_test!("2G", Jedec, 2, Any::Gigibyte);
+    lenient_test!("1.5KB", Jedec, 1.5, Any::Kibibyte);
+    lenient_test!("10 MB", Jedec, 10, Any::Mebibyte);
+    lenient_test!("2gB", Jedec, 2, Any::Gigibyte);
+    lenient_test!("1.5Ki", Decimal, 1.5, Any::Kibibyte);
+    lenient_test!("1.5Gi", Decimal, 1.5, Any::Gigibyte);
+    // Regular multiples.
+    lenient_test!("1 B", Binary, 1, Any::Byte);
+    lenient_test!("1.5 MiB", Decimal, 1.5, Any::Mebibyte);
+    lenient_test!("1.5MB", Binary, 1.5, Any::Megabyte);
+
+    lenient_test!("", ParsingError::EmptyInput);
+    lenient_test!("K", ParsingError::MissingValue);
+    lenient_test!("-", ParsingError::MissingValue);
+    lenient_test!("1.2.3K", ParsingError::InvalidValue);
+    lenient_test!("1e5", ParsingError::InvalidMultiple);
+    lenient_test!("1X", ParsingError::InvalidMultiple);
+    lenient_test!("1Xi", ParsingError::InvalidMultiple);
+}
+
+macro_rules! filter_test {
+    ($filter:expr, $input:expr, $want:expr) => {
+        let filter = $filter;
+        assert_eq!(filter.filter_line($input), $want, "input: {:?}", $input);
+    };
+}
+
+#[test]
+fn humanize() {
+    use UnitSystem::*;
+    filter_test!(Filter::humanize(Decimal), "1500", "1.5 kB");
+    filter_test!(
+        Filter::humanize(Decimal),
+        "1500 file.txt",
+        "1.5 kB file.txt"
+    );
+    filter_test!(
+        Filter::humanize(Binary),
+        "  2048 file.txt",
+        "  2 KiB file.txt"
+    );
+    filter_test!(
+        Filter::humanize(Decimal).field(2),
+        "file.txt\t1500",
+        "file.txt\t1.5 kB"
+    );
+    filter_test!(
+        Filter::humanize(Binary).field(1).field(3),
+        "1024 1024 1048576",
+        "1 KiB 1024 1 MiB"
+    );
+    filter_test!(
+        Filter::humanize(Decimal).precision(1),
+        "1234567 file.txt",
+        "1.2 MB file.txt"
+    );
+    // Fields that can't be parsed are left untouched.
+    filter_test!(Filter::humanize(Jedec), "1536 file.txt", "1.5 KB file.txt");
+    filter_test!(Filter::humanize(Jedec).precision(1), "3G", "3.0 GB");
+    filter_test!(Filter::humanize(Jedec), "2T", "2T");
+    filter_test!(Filter::humanize(Decimal).field(2), "size abc", "size abc");
+    filter_test!(Filter::humanize(Decimal).field(3), "1000 1000", "1000 1000");
+    filter_test!(Filter::humanize(Decimal), "", "");
+}
+
+#[test]
+fn dehumanize() {
+    use UnitSystem::*;
+    filter_test!(Filter::dehumanize(Decimal), "1.5K", "1500");
+    filter_test!(Filter::dehumanize(Binary), "1.5K", "1536");
+    filter_test!(Filter::dehumanize(Decimal), "1.5Ki file", "1536 file");
+    filter_test!(Filter::dehumanize(Decimal), "1.5MiB", "1572864");
+    filter_test!(Filter::dehumanize(Decimal), "0.5", "0.5");
+    filter_test!(Filter::dehumanize(Decimal).precision(0), "1.5", "2");
+    filter_test!(Filter::dehumanize(Jedec), "1.5MB", "1572864");
+}
+
+#[test]
+fn delimiter() {
+    use UnitSystem::*;
+    let filter = Filter::humanize(Decimal).delimiter(',').field(2).field(4);
+    filter_test!(&filter, "a,1000,b,2000000", "a,1 kB,b,2 MB");
+    filter_test!(&filter, "a,,b,2000000", "a,,b,2 MB");
+    filter_test!(&filter, ",1000", ",1 kB");
+    filter_test!(&filter, "a, 1000 ,b", "a,1 kB,b");
+    let filter = Filter::dehumanize(Binary).delimiter('→').field(2);
+    filter_test!(&filter, "a→1K→b", "a→1024→b");
+}
+
+#[test]
+fn padding() {
+    use UnitSystem::*;
+    let filter = Filter::humanize(Binary).field(2);
+    filter_test!(
+        filter.clone().padding(Padding::Right(8)),
+        "a 1024 b",
+        "a    1 KiB b"
+    );
+    filter_test!(
+        filter.clone().padding(Padding::Left(8)),
+        "a 1024 b",
+        "a 1 KiB    b"
+    );
+    filter_test!(
+        filter.clone().padding(Padding::Right(2)),
+        "a 1024 b",
+        "a 1 KiB b"
+    );
+
+    let filter = filter.padding(Padding::Preserve);
+    filter_test!(&filter, "a      1048576 b", "a        1 MiB b");
+    filter_test!(&filter, "a 1048576 b", "a   1 MiB b");
+    // Too long, but keeps a separator.
+    filter_test!(&filter, "a 1024 b", "a 1 KiB b");
+
+    let filter = Filter::dehumanize(Decimal).delimiter(',').field(2);
+    filter_test!(
+        filter.clone().padding(Padding::Preserve),
+        "a,     1.5K,b",
+        "a,     1500,b"
+    );
+    filter_test!(filter.padding(Padding::Right(6)), "a,1.5K,b", "a,  1500,b");
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn regex() {
+    use UnitSystem::*;
+    use regex::Regex;
+    let filter = Filter::dehumanize(Binary).regex(Regex::new(r"size=(\S+);").unwrap());
+    filter_test!(&filter, "size=10MiB;next=1K", "size=10485760;next=1K");
+    filter_test!(&filter, "size=1K; size=2K;", "size=1024; size=2048;");
+    filter_test!(&filter, "size=abc;", "size=abc;");
+    let filter = Filter::humanize(Decimal).regex(Regex::new(r"\d+").unwrap());
+    filter_test!(&filter, "copied 1500 of 3000000", "copied 1.5 kB of 3 MB");
+}
+
+#[test]
+fn filter_stream() {
+    let filter = Filter::humanize(UnitSystem::Binary).field(2);
+    let input = "a 1024\nb 2048\r\nc 4096";
+    let mut output = Vec::new();
+    filter.filter(input.as_bytes(), &mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "a 1 KiB\nb 2 KiB\r\nc 4 KiB"
+    );
+}
+
+#[test]
+fn filter_stream_invalid_utf8() {
+    let filter = Filter::humanize(UnitSystem::Binary);
+    let input: &[u8] = b"1048576 \xff.txt\n2048 ok\n10\xff24 x\n\xff 4096\n";
+    let mut output = Vec::new();
+    filter.filter(input, &mut output).unwrap();
+    let want: &[u8] = b"1 MiB \xff.txt\n2 KiB ok\n10\xff24 x\n\xff 4096\n";
+    assert_eq!(output, want);
+
+    let filter = Filter::humanize(UnitSystem::Binary)
+        .field(2)
+        .padding(Padding::Preserve);
+    let input: &[u8] = b"\xff\xfe  1024 \xff\n";
+    let mut output = Vec::new();
+    filter.filter(input, &mut output).unwrap();
+    let want: &[u8] = b"\xff\xfe 1 KiB \xff\n";
+    assert_eq!(output, want);
+}
+
+#[test]
+#[should_panic(expected = "fields start at one")]
+fn field_zero() {
+    let _ = Filter::humanize(UnitSystem::Decimal).field(0);
+}
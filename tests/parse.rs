@@ -0,0 +1,225 @@
+use human_size::{Any, Kibibyte, Multiple, ParsingError, Size, SpecificSize};
+use proptest::prelude::*;
+
+mod reference;
+
+use reference::reference_parse;
+
+/// Assert that parsing `input` gives the exact same result as the reference
+/// implementation.
+fn assert_same<M>(input: &str)
+where
+    M: Multiple + Copy + PartialEq + std::fmt::Debug,
+{
+    let want = reference_parse(input).map(|size| size.into::<M>());
+    let got = input.parse::<SpecificSize<M>>();
+    match (got, want) {
+        (Ok(got), Ok(want)) => {
+            assert_eq!(
+                got.value().to_bits(),
+                want.value().to_bits(),
+                "input: {:?}",
+                input
+            );
+            assert_eq!(got.multiple(), want.multiple(), "input: {:?}", input);
+        }
+        (got, want) => assert_eq!(got.map(|_| ()), want.map(|_| ()), "input: {:?}", input),
+    }
+}
+
+const CORPUS: &[&str] = &[
+    "0 B",
+    "0B",
+    "1.0 kB",
+    "123.0 MB",
+    "100 GB",
+    "100GB",
+    "321 TB",
+    "10 PB",
+    "12 EB",
+    "0.100 ZB",
+    ".512 YB",
+    "1 RB",
+    "1.5 QB",
+    "0.0 KB",
+    "1. KiB",
+    "1.KiB",
+    "100 MiB",
+    "100 GiB",
+    "123 TiB",
+    "512 PiB",
+    "312 EiB",
+    "1 ZiB",
+    "2 YiB",
+    "3 RiB",
+    "4 QiB",
+    "0 b",
+    "1.0 kb",
+    "123.0 mb",
+    "0.100 zb",
+    "1. kib",
+    "2 yib",
+    "4 qib",
+    "   100   B   ",
+    "12   MiB   ",
+    " \t\t 100 \n\n  B \n  ",
+    "\u{b}100\u{b}B\u{b}",
+    "\u{c}1\rKiB\u{c}",
+    "1000 B",
+    "12 kB",
+    "1 YiB",
+    "1 YB",
+    "9007199254740992 B",
+    "9007199254740993 B",
+    "18446744073709551615 B",
+    "18446744073709551616 B",
+    "99999999999999999999999999999 B",
+    "0000000000000000000000000000001 B",
+    "1e5 B",
+    "0.1 B",
+    "0.3 kB",
+    "9007199254740.992 B",
+    "9007199254740.993 B",
+    "0.0000000000000000000001 B",
+    "0.00000000000000000000001 B",
+    "1.2345678901234567890123 B",
+    "",
+    "   ",
+    "B",
+    "abc MB",
+    "1.0.0 GB",
+    ". B",
+    "..1 B",
+    "10",
+    "10.",
+    "10 abc",
+    "10 B extra",
+    "10 KiBB",
+    "10 Kb",
+    "10 kB",
+    "10 KB",
+    "10 kb",
+    "-10 B",
+    "+10 B",
+    // Non-ASCII input.
+    "\u{a0}10 B\u{a0}",
+    "10\u{2003}KiB",
+    "10 µB",
+    "٣٣ B",
+];
+
+#[test]
+fn same_as_reference() {
+    for input in CORPUS {
+        assert_same::<Any>(input);
+        assert_same::<Kibibyte>(input);
+    }
+}
+
+#[test]
+fn unicode_digits() {
+    // The reference implementation panics on this.
+    assert_eq!("٣ B".parse::<Size>(), Err(ParsingError::InvalidValue));
+}
+
+#[test]
+fn parse_bytes() {
+    for input in CORPUS {
+        let want = input.parse::<Size>();
+        assert_eq!(
+            Size::parse_bytes(input.as_bytes()),
+            want,
+            "input: {:?}",
+            input
+        );
+    }
+
+    let tests: &[(&[u8], Result<Size, ParsingError>)] = &[
+        (b"10 MiB", Ok(SpecificSize::new(10, Any::Mebibyte).unwrap())),
+        (b"\xFF", Err(ParsingError::MissingValue)),
+        (b" \xFF10 B", Err(ParsingError::MissingValue)),
+        (b"10\xFF", Err(ParsingError::InvalidMultiple)),
+        (b"10 \xFF", Err(ParsingError::InvalidMultiple)),
+        (b"10 B\xFF", Err(ParsingError::InvalidMultiple)),
+        (b"1\xFF0 B", Err(ParsingError::InvalidMultiple)),
+        (b"1.0.0\xFF", Err(ParsingError::InvalidValue)),
+    ];
+    for (input, want) in tests {
+        assert_eq!(Size::parse_bytes(input), *want, "input: {:?}", input);
+    }
+}
+
+#[test]
+fn parse_prefix() {
+    type Prefix = Result<(Size, &'static str), ParsingError>;
+    let tests: &[(&str, Prefix)] = &[
+        ("10 B", Ok((SpecificSize::new(10, Any::Byte).unwrap(), ""))),
+        ("10B ", Ok((SpecificSize::new(10, Any::Byte).unwrap(), " "))),
+        (
+            "  1.5\tKiB",
+            Ok((SpecificSize::new(1.5, Any::Kibibyte).unwrap(), "")),
+        ),
+        (
+            "10 B extra",
+            Ok((SpecificSize::new(10, Any::Byte).unwrap(), " extra")),
+        ),
+        (
+            "10MiB;next=20 kB",
+            Ok((SpecificSize::new(10, Any::Mebibyte).unwrap(), ";next=20 kB")),
+        ),
+        (
+            "12 KB,",
+            Ok((SpecificSize::new(12, Any::Kibibyte).unwrap(), ",")),
+        ),
+        (
+            "12 kb2",
+            Ok((SpecificSize::new(12, Any::Kilobyte).unwrap(), "2")),
+        ),
+        (
+            "\u{a0}1 GB\u{a0}",
+            Ok((SpecificSize::new(1, Any::Gigabyte).unwrap(), "\u{a0}")),
+        ),
+        (
+            "1 YiB €",
+            Ok((SpecificSize::new(1, Any::Yobibyte).unwrap(), " €")),
+        ),
+        ("", Err(ParsingError::EmptyInput)),
+        ("   ", Err(ParsingError::EmptyInput)),
+        ("B", Err(ParsingError::MissingValue)),
+        ("; 10 B", Err(ParsingError::MissingValue)),
+        ("٣ B", Err(ParsingError::MissingValue)),
+        ("10", Err(ParsingError::MissingMultiple)),
+        ("10 ;B", Err(ParsingError::MissingMultiple)),
+        ("1.0.0 B", Err(ParsingError::InvalidValue)),
+        (". B", Err(ParsingError::InvalidValue)),
+        ("10 kBps", Err(ParsingError::InvalidMultiple)),
+        ("10 abc", Err(ParsingError::InvalidMultiple)),
+    ];
+    for (input, want) in tests {
+        assert_eq!(Size::parse_prefix(input), *want, "input: {:?}", input);
+    }
+
+    // Without trailing input this is the same as `FromStr`.
+    for input in CORPUS {
+        if let (Ok(want), true) = (input.parse::<Size>(), input.is_ascii()) {
+            let (got, rest) = Size::parse_prefix(input).unwrap();
+            assert_eq!(got, want, "input: {:?}", input);
+            assert_eq!(rest.trim(), "", "input: {:?}", input);
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn same_as_reference_ascii(input in "[ \t]{0,2}[0-9.]{0,22}[ \t]{0,2}[kKmMgGiIbB ]{0,4}") {
+        assert_same::<Any>(&input);
+    }
+
+    #[test]
+    fn same_as_reference_any(input in "\\PC{0,12}") {
+        // Skip input on which the reference implementation panics.
+        let input = input.trim();
+        prop_assume!(input.chars().all(|c| c.is_ascii() || !c.is_numeric()));
+        assert_same::<Any>(input);
+    }
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "proptest")]
+
+use human_size::strategy::{multiple, size, size_string};
+use human_size::{Any, Byte, Kibibyte, Kilobyte, Size, SpecificSize, Yobibyte};
+use proptest::prelude::*;
+
+/// Create a `Display` -> `FromStr` round trip test for `SpecificSize<$type>`.
+macro_rules! round_trip_test {
+    ($name:ident, $type:ty) => {
+        proptest! {
+            #[test]
+            fn $name(size in any::<SpecificSize<$type>>()) {
+                let input = size.to_string();
+                let got: SpecificSize<$type> = input.parse().unwrap();
+                prop_assert_eq!(got.value(), size.value(), "input: {:?}", input);
+                prop_assert_eq!(got.multiple(), size.multiple(), "input: {:?}", input);
+            }
+        }
+    };
+}
+
+round_trip_test!(round_trip_any, Any);
+round_trip_test!(round_trip_byte, Byte);
+round_trip_test!(round_trip_kilobyte, Kilobyte);
+round_trip_test!(round_trip_kibibyte, Kibibyte);
+round_trip_test!(round_trip_yobibyte, Yobibyte);
+
+proptest! {
+    #[test]
+    fn round_trip_precision(size in size::<Any>(), precision in 0..10_usize) {
+        let input = format!("{:.*}", precision, size);
+        let got: Size = input.parse().unwrap();
+        prop_assert_eq!(got.multiple(), size.multiple(), "input: {:?}", input);
+        let want = format!("{:.*}", precision, size.value());
+        prop_assert_eq!(got.value(), want.parse::<f64>().unwrap(), "input: {:?}", input);
+    }
+
+    #[test]
+    fn valid_size_strings(input in size_string()) {
+        let got: Result<Size, _> = input.parse();
+        prop_assert!(got.is_ok(), "input: {:?}, got: {:?}", input, got);
+    }
+
+    #[test]
+    fn parse_prefix_size_strings(input in size_string()) {
+        let want: Size = input.parse().unwrap();
+        let input = format!("{};next", input);
+        let (got, rest) = Size::parse_prefix(&input).unwrap();
+        prop_assert_eq!(got, want, "input: {:?}", input);
+        prop_assert_eq!(rest.trim_start(), ";next", "input: {:?}", input);
+    }
+
+    #[test]
+    fn valid_sizes(size in size::<Kilobyte>()) {
+        prop_assert!(SpecificSize::new(size.value(), Kilobyte).is_ok());
+        prop_assert!(size.value() >= 0.0);
+    }
+}
+
+proptest! {
+    #[test]
+    fn into_equals_source(size in size::<Any>(), multiple in multiple()) {
+        let converted = size.to_multiple(multiple);
+        prop_assume!(converted.value().is_finite());
+        prop_assert_eq!(converted, size);
+        prop_assert_eq!(size, converted);
+
+        let converted: SpecificSize<Kilobyte> = size.into();
+        prop_assume!(converted.value().is_finite());
+        prop_assert_eq!(converted, size);
+        prop_assert_eq!(size, converted);
+        prop_assert_eq!(converted.into::<Any>(), size);
+    }
+}
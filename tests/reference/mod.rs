@@ -0,0 +1,34 @@
+//! The original implementation of `FromStr` for `SpecificSize`, used as
+//! reference in the parsing tests and benchmarks.
+
+use human_size::{Any, ParsingError, Size, SpecificSize};
+
+/// The original implementation of `FromStr` for `SpecificSize`, used as
+/// reference.
+pub fn reference_parse(input: &str) -> Result<Size, ParsingError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(ParsingError::EmptyInput);
+    }
+
+    let multiple_index = input
+        .chars()
+        .position(|c| !(c.is_numeric() || c == '.'))
+        .ok_or(ParsingError::MissingMultiple)?;
+    if multiple_index == 0 {
+        return Err(ParsingError::MissingValue);
+    }
+
+    let (value, multiple) = &input.split_at(multiple_index);
+    let value: f64 = value.parse().map_err(|_| ParsingError::InvalidValue)?;
+    let multiple = multiple.trim();
+    let multiple = if multiple == "KB" {
+        Any::Kibibyte
+    } else {
+        *Any::all()
+            .iter()
+            .find(|m| multiple.eq_ignore_ascii_case(m.symbol()))
+            .ok_or(ParsingError::InvalidMultiple)?
+    };
+    SpecificSize::new(value, multiple).map_err(|_| ParsingError::InvalidValue)
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "rkyv")]
+
+use human_size::{Any, ArchivedSpecificSize, Kibibyte, Size, SpecificSize};
+use rkyv::rancor::Error;
+
+#[test]
+fn archive_specific_multiple() {
+    let size = SpecificSize::new(1.5, Kibibyte).unwrap();
+    let bytes = rkyv::to_bytes::<Error>(&size).unwrap();
+    assert_eq!(bytes.len(), 8);
+
+    let archived = rkyv::access::<ArchivedSpecificSize<Kibibyte>, Error>(&bytes).unwrap();
+    assert_eq!(archived.value(), 1.5);
+    assert_eq!(*archived.multiple(), Kibibyte);
+
+    let got: SpecificSize<Kibibyte> = rkyv::deserialize::<_, Error>(archived).unwrap();
+    assert_eq!(got, size);
+}
+
+#[test]
+fn archive_any_multiple() {
+    let sizes = [
+        SpecificSize::new(0, Any::Byte).unwrap(),
+        SpecificSize::new(10, Any::Megabyte).unwrap(),
+        SpecificSize::new(0.5, Any::Yobibyte).unwrap(),
+    ];
+    let bytes = rkyv::to_bytes::<Error>(&sizes).unwrap();
+
+    let archived = rkyv::access::<[ArchivedSpecificSize<Any>; 3], Error>(&bytes).unwrap();
+    assert_eq!(archived[1].value(), 10.0);
+    assert_eq!(*archived[1].multiple(), Any::Megabyte);
+    assert_eq!(*archived[2].multiple(), Any::Yobibyte);
+
+    let got: [Size; 3] = rkyv::deserialize::<_, Error>(archived).unwrap();
+    assert_eq!(got, sizes);
+}
@@ -1,13 +1,13 @@
 #![cfg(feature = "enable-serde")]
 
-use human_size::{Byte, Kilobyte, Megabyte, Size, SpecificSize, Terabyte};
-use serde_test::{Token, assert_de_tokens_error, assert_tokens};
+use human_size::{Any, Byte, Custom, Kilobyte, Megabyte, Size, SpecificSize, Terabyte};
+use serde_test::{Compact, Configure, Readable, Token, assert_de_tokens_error, assert_tokens};
 
 /// Runs both serialize and deserialize.
 macro_rules! test {
     ($value: expr, $multiple: expr, $want: expr) => {{
         let size = SpecificSize::new($value, $multiple).unwrap();
-        assert_tokens(&size, &[Token::Str($want)]);
+        assert_tokens(&size.readable(), &[Token::Str($want)]);
     }};
 }
 
@@ -21,7 +21,7 @@ fn size_serialize() {
 
 macro_rules! serialize_err_test {
     ($input: expr, $want: expr) => {{
-        assert_de_tokens_error::<Size>(&[Token::Str($input)], $want);
+        assert_de_tokens_error::<Readable<Size>>(&[Token::Str($input)], $want);
     }};
 }
 
@@ -40,3 +40,77 @@ fn size_deserialize_error() {
     serialize_err_test!("10 abc", "invalid multiple");
     serialize_err_test!("10 B extra", "invalid multiple");
 }
+
+/// Runs both serialize and deserialize for a compact (binary) format.
+macro_rules! compact_test {
+    ($value: expr, $multiple: expr, $want: expr) => {{
+        let size = SpecificSize::new($value, $multiple).unwrap();
+        assert_tokens(&size.compact(), $want);
+    }};
+}
+
+#[test]
+fn size_serialize_compact() {
+    compact_test!(100, Byte, &[Token::F64(100.0)]);
+    compact_test!(1.5, Kilobyte, &[Token::F64(1.5)]);
+    compact_test!(0, Megabyte, &[Token::F64(0.0)]);
+
+    #[rustfmt::skip]
+    compact_test!(100, Any::Byte, &[
+        Token::Tuple { len: 2 }, Token::F64(100.0), Token::U8(0), Token::TupleEnd,
+    ]);
+    #[rustfmt::skip]
+    compact_test!(132, Any::Terabyte, &[
+        Token::Tuple { len: 2 }, Token::F64(132.0), Token::U8(4), Token::TupleEnd,
+    ]);
+    #[rustfmt::skip]
+    compact_test!(2.5, Any::Yobibyte, &[
+        Token::Tuple { len: 2 }, Token::F64(2.5), Token::U8(16), Token::TupleEnd,
+    ]);
+    #[rustfmt::skip]
+    compact_test!(1, Any::Quebibyte, &[
+        Token::Tuple { len: 2 }, Token::F64(1.0), Token::U8(20), Token::TupleEnd,
+    ]);
+    // Custom multiples are stored in bytes.
+    #[rustfmt::skip]
+    compact_test!(2, Custom::new("block", "blk", 4096.0), &[
+        Token::Tuple { len: 2 }, Token::F64(8192.0), Token::U8(0), Token::TupleEnd,
+    ]);
+}
+
+#[test]
+fn size_deserialize_compact_error() {
+    assert_de_tokens_error::<Compact<SpecificSize<Byte>>>(&[Token::F64(f64::NAN)], "invalid value");
+    assert_de_tokens_error::<Compact<Size>>(
+        &[Token::Tuple { len: 2 }, Token::F64(1.0), Token::U8(255)],
+        "invalid value: integer `255`, expected multiple",
+    );
+    assert_de_tokens_error::<Compact<Size>>(
+        &[
+            Token::Tuple { len: 2 },
+            Token::F64(f64::INFINITY),
+            Token::U8(0),
+        ],
+        "invalid value",
+    );
+    assert_de_tokens_error::<Compact<Size>>(
+        &[Token::Tuple { len: 2 }, Token::F64(1.0), Token::TupleEnd],
+        "invalid length 1, expected size",
+    );
+}
+
+#[test]
+fn postcard_round_trip() {
+    let size = SpecificSize::new(10, Megabyte).unwrap();
+    let bytes = postcard::to_allocvec(&size).unwrap();
+    assert_eq!(bytes.len(), 8);
+    let got: SpecificSize<Megabyte> = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(got, size);
+
+    let size = SpecificSize::new(10, Any::Mebibyte).unwrap();
+    let bytes = postcard::to_allocvec(&size).unwrap();
+    assert_eq!(bytes.len(), 9);
+    let got: Size = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(got, size);
+    assert_eq!(got.multiple(), Any::Mebibyte);
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "std")]
+
+use std::cmp::Ordering;
+
+use human_size::sort::{SortKey, compare};
+use human_size::{Any, SpecificSize, UnitSystem};
+
+#[test]
+fn compare_tokens() {
+    use Ordering::*;
+    use UnitSystem::*;
+    let tests = [
+        ("1K", "900", Binary, Greater),
+        ("1.5K", "2G", Binary, Less),
+        ("1 KiB", "1K", Decimal, Greater),
+        ("1000K", "1M", Binary, Less),
+        ("10 MiB", "10M", Decimal, Greater),
+        ("-1K", "1", Binary, Less),
+        ("abc", "0", Binary, Less),
+        ("abc", "abd", Binary, Less),
+        ("", "abc", Binary, Less),
+        ("1 kB", "1 kB", Decimal, Equal),
+        // Equal sizes are ordered lexicographically.
+        ("1000K", "1M", Decimal, Less),
+        ("1 KiB", "1K", Binary, Less),
+        ("1M", "1000K", Decimal, Greater),
+    ];
+    for (a, b, system, want) in tests {
+        assert_eq!(
+            compare(a, b, system),
+            want,
+            "{:?} <> {:?} ({:?})",
+            a,
+            b,
+            system
+        );
+        let got = SortKey::new(a, system).cmp(&SortKey::new(b, system));
+        assert_eq!(got, want, "{:?} <> {:?} ({:?})", a, b, system);
+    }
+}
+
+#[test]
+fn sort() {
+    let mut tokens = vec![
+        "2G", "1.5K", "abc", "10 MiB", "900", "", "1M", "-1K", "1 kB", "0.5 GiB",
+    ];
+    tokens.sort_by(|a, b| compare(a, b, UnitSystem::Binary));
+    let want = [
+        "", "abc", "-1K", "900", "1 kB", "1.5K", "1M", "10 MiB", "0.5 GiB", "2G",
+    ];
+    assert_eq!(tokens, want);
+
+    tokens.reverse();
+    tokens.sort_by_cached_key(|token| SortKey::new(token, UnitSystem::Binary));
+    assert_eq!(tokens, want);
+}
+
+#[test]
+fn transitive() {
+    // These sizes are equal within the margin of `PartialEq`, but not
+    // exactly. Comparing them using `PartialOrd`, breaking ties using the
+    // token, orders "1B" after "1.000000006" and "1.000000006" after
+    // "0.001000000012K", but "1B" before "0.001000000012K".
+    let tokens = ["1B", "1.000000006", "0.001000000012K"];
+    for system in [UnitSystem::Decimal, UnitSystem::Binary] {
+        let keys = tokens.map(|token| SortKey::new(token, system));
+        for a in &keys {
+            for b in &keys {
+                for c in &keys {
+                    if a < b && b < c {
+                        assert!(a < c, "{:?} < {:?} < {:?}", a, b, c);
+                    }
+                    assert_eq!(
+                        compare(a.token(), b.token(), system),
+                        a.cmp(b),
+                        "{:?} <> {:?}",
+                        a,
+                        b
+                    );
+                }
+            }
+        }
+    }
+
+    let mut tokens = tokens.to_vec();
+    tokens.sort_by(|a, b| compare(a, b, UnitSystem::Decimal));
+    assert_eq!(tokens, ["1B", "1.000000006", "0.001000000012K"]);
+}
+
+#[test]
+fn sort_key() {
+    let key = SortKey::new("1.5K", UnitSystem::Decimal);
+    assert_eq!(
+        key.size(),
+        Some(SpecificSize::new(1.5, Any::Kilobyte).unwrap())
+    );
+    assert_eq!(key.token(), "1.5K");
+    let key = SortKey::new("abc", UnitSystem::Decimal);
+    assert_eq!(key.size(), None);
+    assert_eq!(key.token(), "abc");
+}
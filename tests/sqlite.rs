@@ -0,0 +1,122 @@
+#![cfg(feature = "rusqlite")]
+
+use human_size::sqlite::Text;
+use human_size::{Any, Byte, Gigibyte, Kilobyte, Megabyte, Size, SpecificSize};
+use rusqlite::types::Value;
+use rusqlite::{Connection, Error};
+
+/// Create an in-memory database with a single `value` column, using `type` as
+/// column type.
+fn database(r#type: &str) -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    let _ = conn
+        .execute(&format!("CREATE TABLE sizes (value {})", r#type), ())
+        .unwrap();
+    conn
+}
+
+/// Store `value` in `conn` and read it back as `T`.
+fn round_trip<V, T>(conn: &Connection, value: V) -> rusqlite::Result<T>
+where
+    V: rusqlite::ToSql,
+    T: rusqlite::types::FromSql,
+{
+    let _ = conn.execute("DELETE FROM sizes", ())?;
+    let _ = conn.execute("INSERT INTO sizes VALUES (?1)", (value,))?;
+    conn.query_row("SELECT value FROM sizes", (), |row| row.get(0))
+}
+
+/// Store `value` in `conn` and read it back as raw SQLite value.
+fn stored<V: rusqlite::ToSql>(conn: &Connection, value: V) -> Value {
+    round_trip(conn, value).unwrap()
+}
+
+#[test]
+fn integer_storage() {
+    let conn = database("INTEGER");
+
+    let size = SpecificSize::new(10, Kilobyte).unwrap();
+    assert_eq!(stored(&conn, size), Value::Integer(10_000));
+    let size = SpecificSize::new(1, Gigibyte).unwrap();
+    assert_eq!(stored(&conn, size), Value::Integer(1_073_741_824));
+    let size = SpecificSize::new(0, Any::Megabyte).unwrap();
+    assert_eq!(stored(&conn, size), Value::Integer(0));
+
+    let size = SpecificSize::new(1.5, Kilobyte).unwrap();
+    let got: SpecificSize<Kilobyte> = round_trip(&conn, size).unwrap();
+    assert_eq!(got, size);
+    assert_eq!(got.to_string(), "1.5 kB");
+    let got: SpecificSize<Byte> = round_trip(&conn, size).unwrap();
+    assert_eq!(got.to_string(), "1500 B");
+    let got: Size = round_trip(&conn, size).unwrap();
+    assert_eq!(got.to_string(), "1500 B");
+
+    // 32.3 kB is 32299.999999999996 bytes as `f64`.
+    let size = SpecificSize::new(32.3, Kilobyte).unwrap();
+    assert_eq!(stored(&conn, size), Value::Integer(32_300));
+    let got: SpecificSize<Kilobyte> = round_trip(&conn, size).unwrap();
+    assert_eq!(got, size);
+    assert_eq!(got.to_string(), "32.3 kB");
+
+    let size = SpecificSize::new(-1.5, Kilobyte).unwrap();
+    assert_eq!(stored(&conn, size), Value::Integer(-1500));
+}
+
+#[test]
+fn integer_storage_errors() {
+    let conn = database("INTEGER");
+
+    // Fractional bytes.
+    let size = SpecificSize::new(1.5, Byte).unwrap();
+    let err = round_trip::<_, Size>(&conn, size).unwrap_err();
+    assert!(matches!(err, Error::ToSqlConversionFailure(_)), "{:?}", err);
+    assert_eq!(
+        err.to_string(),
+        "size is not a whole number of bytes that fits in an INTEGER"
+    );
+
+    // Too large for an INTEGER.
+    let size = SpecificSize::new(16, Any::Exbibyte).unwrap();
+    let err = round_trip::<_, Size>(&conn, size).unwrap_err();
+    assert!(matches!(err, Error::ToSqlConversionFailure(_)), "{:?}", err);
+}
+
+#[test]
+fn text_storage() {
+    let conn = database("TEXT");
+
+    let size = SpecificSize::new(1.5, Megabyte).unwrap();
+    assert_eq!(stored(&conn, Text(size)), Value::Text("1.5 MB".to_owned()));
+    let size = SpecificSize::new(16, Any::Exbibyte).unwrap();
+    assert_eq!(stored(&conn, Text(size)), Value::Text("16 EiB".to_owned()));
+
+    let got: Text<Any> = round_trip(&conn, Text(size)).unwrap();
+    assert_eq!(got.0, size);
+    assert_eq!(got.0.multiple(), Any::Exbibyte);
+    // Text can also be read without the wrapper.
+    let got: SpecificSize<Byte> = round_trip(&conn, Text(size)).unwrap();
+    assert_eq!(got, size);
+}
+
+/// Returns the error message of the `FromSql` conversion failure.
+fn conversion_error(err: Error) -> String {
+    match err {
+        Error::FromSqlConversionFailure(_, _, err) => err.to_string(),
+        err => panic!("unexpected error: {:?}", err),
+    }
+}
+
+#[test]
+fn reading_errors() {
+    let conn = database("");
+
+    let err = round_trip::<_, Size>(&conn, "10 abc").unwrap_err();
+    assert_eq!(conversion_error(err), "invalid multiple");
+    let err = round_trip::<_, Size>(&conn, f64::INFINITY).unwrap_err();
+    assert_eq!(conversion_error(err), "invalid value");
+
+    let err = round_trip::<_, Size>(&conn, Value::Null).unwrap_err();
+    assert!(matches!(err, Error::InvalidColumnType(..)), "{:?}", err);
+    let err = round_trip::<_, Size>(&conn, vec![1u8, 2, 3]).unwrap_err();
+    assert!(matches!(err, Error::InvalidColumnType(..)), "{:?}", err);
+}
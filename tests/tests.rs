@@ -57,6 +57,8 @@ fn simple_size_parsing() {
     parse_test!("12 EB", 12, Exabyte);
     parse_test!("0.100 ZB", 0.1, Zettabyte);
     parse_test!(".512 YB", 0.512, Yottabyte);
+    parse_test!("1 RB", 1, Ronnabyte);
+    parse_test!("1.5 QB", 1.5, Quettabyte);
 
     // Multiples of 1024.
     parse_test!("0.0 KB", 0, Kibibyte);
@@ -69,6 +71,8 @@ fn simple_size_parsing() {
     parse_test!("312 EiB", 312, Exbibyte);
     parse_test!("1 ZiB", 1, Zebibyte);
     parse_test!("2 YiB", 2, Yobibyte);
+    parse_test!("3 RiB", 3, Robibyte);
+    parse_test!("4 QiB", 4, Quebibyte);
 
     // Same as above, but then using `Any`.
     parse_test!("0 B", 0, Any::Byte);
@@ -82,6 +86,8 @@ fn simple_size_parsing() {
     parse_test!("12 EB", 12, Any::Exabyte);
     parse_test!("0.100 ZB", 0.1, Any::Zettabyte);
     parse_test!(".512 YB", 0.512, Any::Yottabyte);
+    parse_test!("1 RB", 1, Any::Ronnabyte);
+    parse_test!("1.5 QB", 1.5, Any::Quettabyte);
 
     parse_test!("0.0 KB", 0, Any::Kibibyte);
     parse_test!("1. KiB", 1, Any::Kibibyte);
@@ -92,6 +98,8 @@ fn simple_size_parsing() {
     parse_test!("312 EiB", 312, Any::Exbibyte);
     parse_test!("1 ZiB", 1, Any::Zebibyte);
     parse_test!("2 YiB", 2, Any::Yobibyte);
+    parse_test!("3 RiB", 3, Any::Robibyte);
+    parse_test!("4 QiB", 4, Any::Quebibyte);
 
     // Test lowercase and uppercase are both accepted.
     parse_test!("0 b", 0, Any::Byte);
@@ -105,6 +113,8 @@ fn simple_size_parsing() {
     parse_test!("12 eb", 12, Any::Exabyte);
     parse_test!("0.100 zb", 0.1, Any::Zettabyte);
     parse_test!(".512 yb", 0.512, Any::Yottabyte);
+    parse_test!("1 rb", 1, Any::Ronnabyte);
+    parse_test!("1 qb", 1, Any::Quettabyte);
 
     parse_test!("1. kib", 1, Any::Kibibyte);
     parse_test!("100 mib", 100, Any::Mebibyte);
@@ -114,6 +124,8 @@ fn simple_size_parsing() {
     parse_test!("312 eib", 312, Any::Exbibyte);
     parse_test!("1 zib", 1, Any::Zebibyte);
     parse_test!("2 yib", 2, Any::Yobibyte);
+    parse_test!("3 rib", 3, Any::Robibyte);
+    parse_test!("4 qib", 4, Any::Quebibyte);
 
     // Accept some extra white space.
     parse_test!("   100   B   ", 100, Byte);
@@ -128,8 +140,8 @@ fn parsing_size_conversion() {
     parse_test!("1 YiB", 1208925819614629174706176.0, Byte);
     parse_test!("1 YB", 1000000000000000000000000.0, Byte);
 
-    // This is where floats lose there precision.
-    parse_test!("100 MiB", 104.85759999999999, Megabyte);
+    // Converting through bytes keeps the precision.
+    parse_test!("100 MiB", 104.8576, Megabyte);
 
     // TODO: Add more conversion tests.
 }
@@ -171,6 +183,8 @@ fn displaying_size() {
     display_test!(12, Exabyte, "12 EB");
     display_test!(0.1, Zettabyte, "0.1 ZB");
     display_test!(0.512, Yottabyte, "0.512 YB");
+    display_test!(1, Ronnabyte, "1 RB");
+    display_test!(1.5, Quettabyte, "1.5 QB");
 
     // Multiples of 1024.
     display_test!(0, Kibibyte, "0 KiB");
@@ -182,6 +196,8 @@ fn displaying_size() {
     display_test!(312, Exbibyte, "312 EiB");
     display_test!(1, Zebibyte, "1 ZiB");
     display_test!(2, Yobibyte, "2 YiB");
+    display_test!(3, Robibyte, "3 RiB");
+    display_test!(4, Quebibyte, "4 QiB");
 
     // Same but for `Any`.
     display_test!(0, Any::Byte, "0 B");
@@ -194,6 +210,8 @@ fn displaying_size() {
     display_test!(12, Any::Exabyte, "12 EB");
     display_test!(0.1, Any::Zettabyte, "0.1 ZB");
     display_test!(0.512, Any::Yottabyte, "0.512 YB");
+    display_test!(1, Any::Ronnabyte, "1 RB");
+    display_test!(1.5, Any::Quettabyte, "1.5 QB");
 
     display_test!(0, Any::Kibibyte, "0 KiB");
     display_test!(1.9999, Any::Kibibyte, "1.9999 KiB");
@@ -204,6 +222,8 @@ fn displaying_size() {
     display_test!(312, Any::Exbibyte, "312 EiB");
     display_test!(1, Any::Zebibyte, "1 ZiB");
     display_test!(2, Any::Yobibyte, "2 YiB");
+    display_test!(3, Any::Robibyte, "3 RiB");
+    display_test!(4, Any::Quebibyte, "4 QiB");
 
     // Test provided precision.
     let input = SpecificSize::new(1.1234567890, Byte).unwrap();
@@ -242,6 +262,12 @@ fn equivalence_tests() {
     equivalence_test!(1000f64.powi(7), Byte, 1, Any::Zettabyte);
     equivalence_test!(1000f64.powi(8), Byte, 1, Yottabyte);
     equivalence_test!(1000f64.powi(8), Byte, 1, Any::Yottabyte);
+    equivalence_test!(1000f64.powi(9), Byte, 1, Ronnabyte);
+    equivalence_test!(1000f64.powi(9), Byte, 1, Any::Ronnabyte);
+    equivalence_test!(1000f64.powi(10), Byte, 1, Quettabyte);
+    equivalence_test!(1000f64.powi(10), Byte, 1, Any::Quettabyte);
+    equivalence_test!(1000, Yottabyte, 1, Ronnabyte);
+    equivalence_test!(1000, Ronnabyte, 1, Quettabyte);
 
     equivalence_test!(1024, Byte, 1, Kibibyte);
     equivalence_test!(1024, Byte, 1, Any::Kibibyte);
@@ -259,6 +285,12 @@ fn equivalence_tests() {
     equivalence_test!(1024f64.powi(7), Byte, 1, Any::Zebibyte);
     equivalence_test!(1024f64.powi(8), Byte, 1, Yobibyte);
     equivalence_test!(1024f64.powi(8), Byte, 1, Any::Yobibyte);
+    equivalence_test!(1024f64.powi(9), Byte, 1, Robibyte);
+    equivalence_test!(1024f64.powi(9), Byte, 1, Any::Robibyte);
+    equivalence_test!(1024f64.powi(10), Byte, 1, Quebibyte);
+    equivalence_test!(1024f64.powi(10), Byte, 1, Any::Quebibyte);
+    equivalence_test!(1024, Yobibyte, 1, Robibyte);
+    equivalence_test!(1024, Robibyte, 1, Quebibyte);
 
     equivalence_test!(1073.741824f64, Megabyte, 1, Gigibyte);
     equivalence_test!(1073.741824f64, Megabyte, 1, Any::Gigibyte);
@@ -280,6 +312,7 @@ macro_rules! ordering_test {
 }
 
 #[test]
+#[allow(clippy::zero_prefixed_literal)]
 fn ordering_tests() {
     use std::cmp::Ordering::*;
     ordering_test!(1, Byte, Equal, 1, Byte);
@@ -298,6 +331,35 @@ fn ordering_tests() {
     ordering_test!(1, Kilobyte, Less, 1, Kibibyte);
 }
 
+#[test]
+fn ordering_consistent_with_equality() {
+    use std::cmp::Ordering::*;
+    let tests = [
+        ("1000000000000 B", "1000000000000.0009 B", Equal),
+        ("1000000000000 B", "1000000000002 B", Less),
+        ("1 YB", "1.0000000000005 YB", Equal),
+        ("1 YB", "1.000000001 YB", Less),
+        ("0.000000001 B", "0 B", Equal),
+        ("100 MiB", "104.8576 MB", Equal),
+        ("2 B", "1 B", Greater),
+    ];
+    for (left, right, want) in tests {
+        let left: Size = left.parse().unwrap();
+        let right: Size = right.parse().unwrap();
+        assert_eq!(left.partial_cmp(&right), Some(want), "{} {}", left, right);
+        assert_eq!(
+            right.partial_cmp(&left),
+            Some(want.reverse()),
+            "{} {}",
+            right,
+            left
+        );
+        assert_eq!(left == right, want == Equal, "{} {}", left, right);
+        assert_eq!(left < right, want == Less, "{} {}", left, right);
+        assert_eq!(left > right, want == Greater, "{} {}", left, right);
+    }
+}
+
 macro_rules! into_test {
     ($size_left:expr, $type_left:expr, $size_right:expr, $type_right:expr, $tr:ty) => {
         let left = SpecificSize::new($size_left, $type_left).unwrap();
@@ -327,4 +389,792 @@ fn to_bytes_tests() {
     to_bytes_test!(1, Kilobyte, 1000);
     to_bytes_test!(1, Kilobyte, 1000);
     to_bytes_test!(2.3, Tebibyte, 2528876743884);
+    to_bytes_test!(1.5, Byte, 1);
+    to_bytes_test!(-1, Kilobyte, 0);
+    // Saturates.
+    to_bytes_test!(1, Yobibyte, u64::MAX);
+    to_bytes_test!(16, Exbibyte, u64::MAX);
+
+    // Largest `f64` smaller than `u64::MAX`.
+    let below_max = SpecificSize::new(18446744073709549568.0, Byte).unwrap();
+    assert_eq!(below_max.to_bytes(), 18446744073709549568);
+    assert_eq!(below_max.try_to_bytes(), Ok(18446744073709549568));
+    assert_eq!(below_max.to_bytes_saturating(), 18446744073709549568);
+    // `u64::MAX as f64` is rounded up to 2^64.
+    let max = SpecificSize::new(u64::MAX as f64, Byte).unwrap();
+    assert_eq!(max.try_to_bytes(), Err(TryFromSizeError::Overflow));
+    assert_eq!(max.to_bytes_u128(), Ok(1 << 64));
+    assert_eq!(max.to_bytes_saturating(), u64::MAX);
+    let size = SpecificSize::new(15, Exbibyte).unwrap();
+    assert_eq!(size.try_to_bytes(), Ok(15 << 60));
+}
+
+#[test]
+fn to_bytes_fallible() {
+    let size = SpecificSize::new(1.5, Byte).unwrap();
+    assert_eq!(size.try_to_bytes(), Err(TryFromSizeError::Fractional));
+    assert_eq!(size.to_bytes_u128(), Err(TryFromSizeError::Fractional));
+    let size = SpecificSize::new(32.3, Kilobyte).unwrap();
+    assert_eq!(size.to_bytes(), 32299);
+    assert_eq!(size.try_to_bytes(), Ok(32300));
+    assert_eq!(size.to_bytes_saturating(), 32300);
+    let size = SpecificSize::new(-1, Byte).unwrap();
+    assert_eq!(size.try_to_bytes(), Err(TryFromSizeError::Negative));
+    assert_eq!(size.to_bytes_saturating(), 0);
+    let size = SpecificSize::new(-0.5, Byte).unwrap();
+    assert_eq!(size.try_to_bytes(), Err(TryFromSizeError::Negative));
+    assert_eq!(size.to_bytes_saturating(), 0);
+
+    let size = SpecificSize::new(1, Yobibyte).unwrap();
+    assert_eq!(size.try_to_bytes(), Err(TryFromSizeError::Overflow));
+    assert_eq!(size.to_bytes_u128(), Ok(1 << 80));
+    // Whole values are exact, even if the bytes can't be represented as `f64`.
+    let size = SpecificSize::new(1, Quettabyte).unwrap();
+    assert_eq!(size.to_bytes_u128(), Ok(Quettabyte::BYTES_U128));
+    let size = SpecificSize::new(1, Yottabyte).unwrap();
+    assert_eq!(size.to_bytes_u128(), Ok(Yottabyte::BYTES_U128));
+    assert_eq!(u128::try_from(size), Ok(Yottabyte::BYTES_U128));
+    let size = SpecificSize::new(7, Any::Ronnabyte).unwrap();
+    assert_eq!(size.to_bytes_u128(), Ok(7 * Ronnabyte::BYTES_U128));
+    let size = SpecificSize::new(12345, Exabyte).unwrap();
+    assert_eq!(size.to_bytes_u128(), Ok(12345 * Exabyte::BYTES_U128));
+    let size = SpecificSize::new(1e17, Zettabyte).unwrap();
+    assert_eq!(
+        size.to_bytes_u128(),
+        Ok(100_000_000_000_000_000 * Zettabyte::BYTES_U128)
+    );
+    let size = SpecificSize::new(1e18, Quettabyte).unwrap();
+    assert_eq!(size.to_bytes_u128(), Err(TryFromSizeError::Overflow));
+    // Fractional values are limited by the precision of `f64`.
+    let size = SpecificSize::new(1.5, Yottabyte).unwrap();
+    assert_eq!(size.to_bytes_u128(), Ok((1.5 * Yottabyte::BYTES) as u128));
+    let size = SpecificSize::new(1e10, Quebibyte).unwrap();
+    assert_eq!(size.to_bytes_u128(), Err(TryFromSizeError::Overflow));
+}
+
+macro_rules! to_bytes_rounded_test {
+    ($value:expr, $multiple:expr, $down:expr, $up:expr, $nearest:expr) => {
+        let size = SpecificSize::new($value, $multiple).unwrap();
+        assert_eq!(size.to_bytes_rounded(Rounding::Down), $down, "{}", size);
+        assert_eq!(size.to_bytes_rounded(Rounding::Up), $up, "{}", size);
+        assert_eq!(
+            size.to_bytes_rounded(Rounding::Nearest),
+            $nearest,
+            "{}",
+            size
+        );
+    };
+}
+
+#[test]
+fn to_bytes_rounded_tests() {
+    to_bytes_rounded_test!(0, Byte, Ok(0), Ok(0), Ok(0));
+    to_bytes_rounded_test!(1.2, Byte, Ok(1), Ok(2), Ok(1));
+    to_bytes_rounded_test!(1.5, Byte, Ok(1), Ok(2), Ok(2));
+    to_bytes_rounded_test!(1.7, Byte, Ok(1), Ok(2), Ok(2));
+    to_bytes_rounded_test!(
+        2.3,
+        Tebibyte,
+        Ok(2528876743884),
+        Ok(2528876743885),
+        Ok(2528876743885)
+    );
+    // Rounding errors are ignored.
+    to_bytes_rounded_test!(0.3, Kilobyte, Ok(300), Ok(300), Ok(300));
+    to_bytes_rounded_test!(1.1, Kilobyte, Ok(1100), Ok(1100), Ok(1100));
+    to_bytes_rounded_test!(32.3, Kilobyte, Ok(32300), Ok(32300), Ok(32300));
+    // Even if that means rounding up when rounding down.
+    to_bytes_rounded_test!(0.999999995, Byte, Ok(1), Ok(1), Ok(1));
+    to_bytes_rounded_test!(1.000000005, Byte, Ok(1), Ok(1), Ok(1));
+    to_bytes_rounded_test!(-0.000000005, Byte, Ok(0), Ok(0), Ok(0));
+
+    let overflow = Err(TryFromSizeError::Overflow);
+    to_bytes_rounded_test!(1, Yobibyte, overflow, overflow, overflow);
+    let negative = Err(TryFromSizeError::Negative);
+    to_bytes_rounded_test!(-1, Byte, negative, negative, negative);
+    to_bytes_rounded_test!(-1.5, Byte, negative, negative, negative);
+    to_bytes_rounded_test!(-0.7, Byte, negative, Ok(0), negative);
+    // Negative sizes can be rounded to zero.
+    to_bytes_rounded_test!(-0.5, Byte, negative, Ok(0), Ok(0));
+    to_bytes_rounded_test!(-0.3, Byte, negative, Ok(0), Ok(0));
+}
+
+macro_rules! arithmetic_test {
+    ($size_left:expr, $type_left:expr, $op:tt, $size_right:expr, $type_right:expr, $expected:expr) => {
+        let left = SpecificSize::new($size_left, $type_left).unwrap();
+        let right = SpecificSize::new($size_right, $type_right).unwrap();
+        assert_eq!((left $op right).to_string(), $expected);
+    };
+}
+
+#[test]
+fn arithmetic_tests() {
+    arithmetic_test!(1, Byte, +, 1, Byte, "2 B");
+    arithmetic_test!(1, Kilobyte, +, 500, Byte, "1.5 kB");
+    arithmetic_test!(500, Byte, +, 1, Kilobyte, "1500 B");
+    arithmetic_test!(1, Any::Kibibyte, +, 1, Kibibyte, "2 KiB");
+    arithmetic_test!(1, Any::Mebibyte, +, 512, Any::Kibibyte, "1.5 MiB");
+
+    arithmetic_test!(2, Byte, -, 1, Byte, "1 B");
+    arithmetic_test!(1, Kilobyte, -, 500, Byte, "0.5 kB");
+    arithmetic_test!(1, Any::Mebibyte, -, 512, Any::Kibibyte, "0.5 MiB");
+    arithmetic_test!(1, Kilobyte, -, 2, Kilobyte, "-1 kB");
+
+    let max = SpecificSize::new(f64::MAX, Byte).unwrap();
+    assert_eq!(max.checked_add(max), None);
+    let min = SpecificSize::new(f64::MIN, Byte).unwrap();
+    assert_eq!(min.checked_sub(max), None);
+    let one = SpecificSize::new(1, Byte).unwrap();
+    assert_eq!(
+        one.checked_add(one),
+        Some(SpecificSize::new(2, Byte).unwrap())
+    );
+}
+
+#[test]
+#[should_panic(expected = "overflow when adding sizes")]
+fn add_overflow() {
+    let max = SpecificSize::new(f64::MAX, Byte).unwrap();
+    let _ = max + max;
+}
+
+macro_rules! to_multiple_test {
+    ($value:expr, $multiple:expr, $to:expr, $expected:expr) => {
+        let size = SpecificSize::new($value, $multiple).unwrap();
+        let got = size.to_multiple($to);
+        assert_eq!(got.to_string(), $expected, "input: {:?}", size);
+        assert_eq!(got, size);
+    };
+}
+
+#[test]
+fn to_multiple_tests() {
+    to_multiple_test!(1, Kilobyte, Any::Byte, "1000 B");
+    to_multiple_test!(1, Any::Kibibyte, Any::Byte, "1024 B");
+    to_multiple_test!(1500, Byte, Any::Kilobyte, "1.5 kB");
+    to_multiple_test!(10, Gigibyte, Any::Megabyte, "10737.41824 MB");
+    to_multiple_test!(1, Any::Yobibyte, Any::Yobibyte, "1 YiB");
+    to_multiple_test!(
+        f64::MAX,
+        Kibibyte,
+        Any::Mebibyte,
+        format!("{} MiB", f64::MAX / 1024.0)
+    );
+}
+
+macro_rules! to_system_test {
+    ($value:expr, $multiple:expr, $system:expr, $expected:expr) => {
+        let size = SpecificSize::new($value, $multiple).unwrap();
+        let got = size.to_system($system);
+        assert_eq!(got.to_string(), $expected, "input: {:?}", size);
+    };
+}
+
+#[test]
+fn to_system_tests() {
+    use UnitSystem::*;
+    to_system_test!(0, Byte, Decimal, "0 B");
+    to_system_test!(999, Byte, Decimal, "999 B");
+    to_system_test!(1000, Byte, Decimal, "1 kB");
+    to_system_test!(1000, Byte, Binary, "1000 B");
+    to_system_test!(1024, Byte, Binary, "1 KiB");
+    to_system_test!(1_572_864, Byte, Decimal, "1.572864 MB");
+    to_system_test!(1_572_864, Byte, Binary, "1.5 MiB");
+    to_system_test!(0.5, Kilobyte, Decimal, "500 B");
+    to_system_test!(2048, Any::Gigibyte, Binary, "2 TiB");
+    to_system_test!(2000, Any::Yottabyte, Decimal, "2 RB");
+    to_system_test!(2_000_000, Any::Yottabyte, Decimal, "2 QB");
+    to_system_test!(2000, Any::Quettabyte, Decimal, "2000 QB");
+    to_system_test!(1024, Any::Yobibyte, Binary, "1 RiB");
+    to_system_test!(2048, Any::Robibyte, Binary, "2 QiB");
+    to_system_test!(4096, Any::Quebibyte, Binary, "4096 QiB");
+    to_system_test!(1023, Byte, Jedec, "1023 B");
+    to_system_test!(1536, Byte, Jedec, "1.5 KiB");
+    to_system_test!(1, Any::Gigabyte, Jedec, "953.67431640625 MiB");
+    to_system_test!(2, Any::Tebibyte, Jedec, "2048 GiB");
+    to_system_test!(1500, Byte, UnitSystem::SI, "1.5 kB");
+    to_system_test!(1536, Byte, UnitSystem::IEC, "1.5 KiB");
+    to_system_test!(-1500, Byte, Decimal, "-1.5 kB");
+}
+
+#[test]
+fn to_decimal_and_binary() {
+    let size = SpecificSize::new(1.5, Mebibyte).unwrap();
+    assert_eq!(size.to_decimal().to_string(), "1.572864 MB");
+    assert_eq!(size.to_binary().to_string(), "1.5 MiB");
+    assert_eq!(size.to_decimal(), size.to_system(UnitSystem::Decimal));
+    assert_eq!(size.to_binary().multiple(), Any::Mebibyte);
+
+    let size = SpecificSize::new(0.5, Kilobyte).unwrap();
+    assert_eq!(size.to_decimal().to_string(), "500 B");
+    assert_eq!(size.to_binary().to_string(), "500 B");
+}
+
+#[test]
+fn to_jedec() {
+    let size = SpecificSize::new(1.5, Mebibyte).unwrap();
+    assert_eq!(size.to_jedec().to_string(), "1.5 MB");
+    assert_eq!(size.to_jedec().size(), size.to_system(UnitSystem::Jedec));
+    assert_eq!(size.to_jedec().size().multiple(), Any::Mebibyte);
+    assert_eq!(format!("{:.2}", size.to_jedec()), "1.50 MB");
+
+    let size = SpecificSize::new(1536, Byte).unwrap();
+    assert_eq!(size.to_jedec().to_string(), "1.5 KB");
+    let size = SpecificSize::new(1, Gigabyte).unwrap();
+    assert_eq!(size.to_jedec().to_string(), "953.67431640625 MB");
+    let size = SpecificSize::new(2, Any::Tebibyte).unwrap();
+    assert_eq!(size.to_jedec().to_string(), "2048 GB");
+    let size = SpecificSize::new(1000, Byte).unwrap();
+    assert_eq!(size.to_jedec().to_string(), "1000 B");
+    let size = SpecificSize::new(-2, Any::Kibibyte).unwrap();
+    assert_eq!(size.to_jedec().to_string(), "-2 KB");
+    // "KB" is parsed as kibibyte.
+    let size = SpecificSize::new(2, Any::Kibibyte).unwrap();
+    assert_eq!(size.to_jedec().to_string().parse::<Size>(), Ok(size));
+}
+
+#[test]
+fn multiple_constants() {
+    assert_eq!(Byte::BYTES, 1.0);
+    assert_eq!(Kilobyte::BYTES, 1000.0);
+    assert_eq!(Gigibyte::BYTES, 1073741824.0);
+    assert_eq!(Yottabyte::BYTES, 1e24);
+    assert_eq!(Byte::BYTES_U128, 1);
+    assert_eq!(Megabyte::BYTES_U128, 1_000_000);
+    assert_eq!(Yottabyte::BYTES_U128, 1_000_000_000_000_000_000_000_000);
+    assert_eq!(Yobibyte::BYTES_U128, 1 << 80);
+    assert_eq!(Ronnabyte::BYTES, 1e27);
+    assert_eq!(Quettabyte::BYTES, 1e30);
+    assert_eq!(Quettabyte::BYTES_U128, 10_u128.pow(30));
+    assert_eq!(Robibyte::BYTES_U128, 1 << 90);
+    assert_eq!(Quebibyte::BYTES_U128, 1 << 100);
+    assert_eq!(Quebibyte::BYTES, 2f64.powi(100));
+}
+
+#[test]
+fn multiple_metadata() {
+    let all = Any::all();
+    assert_eq!(all.len(), 21);
+    assert_eq!(all[0], Any::Byte);
+    for (i, multiple) in all.iter().enumerate() {
+        let bytes = multiple.multiple_of_bytes_u128();
+        assert_eq!(multiple.multiple_of_bytes(), bytes as f64);
+        assert_eq!(multiple.symbol().parse::<Any>(), Ok(*multiple));
+        assert_eq!(multiple.symbol(), multiple.to_string());
+        let base: u128 = match multiple.system() {
+            None => 1,
+            Some(UnitSystem::Decimal) => 1000,
+            Some(UnitSystem::Binary) => 1024,
+            Some(_) => unreachable!(),
+        };
+        assert_eq!(bytes, base.pow(multiple.exponent()), "{}", multiple);
+        // Sorted from small to large within each system.
+        if let Some(next) = all.get(i + 1)
+            && next.system() == multiple.system()
+        {
+            assert!(next.multiple_of_bytes_u128() > bytes);
+        }
+    }
+
+    assert_eq!(Any::Kilobyte.name(), "kilobyte");
+    assert_eq!(Any::Gigibyte.name(), "gibibyte");
+    assert_eq!(Any::Byte.system(), None);
+    assert_eq!(Any::Megabyte.system(), Some(UnitSystem::Decimal));
+    assert_eq!(Any::Mebibyte.system(), Some(UnitSystem::Binary));
+    assert_eq!(Any::Quettabyte.name(), "quettabyte");
+    assert_eq!(Any::Quebibyte.exponent(), 10);
+    assert_eq!(Any::Mebibyte.exponent(), 2);
+}
+
+#[test]
+fn largest_multiples_range() {
+    // The largest values are still valid, but can't be represented in bytes.
+    let size = SpecificSize::new(f64::MAX, Quettabyte).unwrap();
+    assert!(size.into::<Byte>().value().is_infinite());
+    assert_eq!(size.checked_add(size), None);
+
+    let size = SpecificSize::new(1e300, Quebibyte).unwrap();
+    assert_eq!(size.into::<Byte>().value(), 1e300 * 2f64.powi(100));
+    let size = SpecificSize::new(f64::MAX, Byte).unwrap();
+    assert_eq!(
+        size.to_system(UnitSystem::Decimal).multiple(),
+        Any::Quettabyte
+    );
+    assert_eq!(
+        size.to_system(UnitSystem::Binary).multiple(),
+        Any::Quebibyte
+    );
+}
+
+#[test]
+fn const_construction() {
+    const MAX_BODY: SpecificSize<Mebibyte> = SpecificSize::<Mebibyte>::from_const(10.0);
+    const MAX_BODY_SIZE: Size = MAX_BODY.to_size();
+    const MAX_BODY_KIB: SpecificSize<Kibibyte> = SpecificSize::<Kibibyte>::from_size(MAX_BODY_SIZE);
+    const ZERO: Size = SpecificSize::new_const(0.0, Any::Byte);
+
+    assert_eq!(MAX_BODY.to_string(), "10 MiB");
+    assert_eq!(MAX_BODY_SIZE.multiple(), Any::Mebibyte);
+    assert_eq!(MAX_BODY_KIB.value(), 10240.0);
+    assert_eq!(MAX_BODY, MAX_BODY_KIB);
+    assert_eq!(ZERO.to_bytes(), 0);
+}
+
+#[test]
+#[should_panic = "invalid size value"]
+fn const_construction_invalid() {
+    let _ = SpecificSize::<Byte>::from_const(f64::NAN);
+}
+
+#[test]
+fn size_macro() {
+    const SIZE: Size = size!("10 MiB");
+    assert_eq!(SIZE, SpecificSize::new(10, Mebibyte).unwrap());
+    assert_eq!(size!(" 1.5kb ").to_string(), "1.5 kB");
+    assert_eq!(size!("2 KB").multiple(), Any::Kibibyte);
+}
+
+#[test]
+fn parse_const() {
+    let tests = &[
+        "10 MiB",
+        "1.5 GB",
+        ".5 kB",
+        "5. B",
+        "0 B",
+        " 100\tkib\n",
+        "0.1 B",
+        "123456789.123456789 B",
+        "99999999999999999999999999999 YB",
+        "0.000000000000000000000000000001 QB",
+        "12345678901234567890.12345678901234567890 B",
+        "2 KB",
+        "1 Quettabyte",
+        "",
+        "   ",
+        "B",
+        "10",
+        "1.2.3 B",
+        ". B",
+        "1 invalid",
+        "1 kB B",
+    ];
+    for input in tests {
+        let want = input.parse::<Size>();
+        let got = Size::parse_const(input);
+        assert_eq!(got, want, "input: {:?}", input);
+        if let (Ok(got), Ok(want)) = (got, want) {
+            assert_eq!(got.value(), want.value(), "input: {:?}", input);
+            assert_eq!(got.multiple(), want.multiple(), "input: {:?}", input);
+        }
+    }
+}
+
+#[test]
+fn parse_const_precision() {
+    // Values that are always the same as using `FromStr`.
+    let exact = &[
+        "9007199254740992 B",
+        "900719925474099.2 B",
+        "0.0000000000000000000001 B",
+        "1234567890.123456 QB",
+    ];
+    for input in exact {
+        let want = input.parse::<Size>().unwrap();
+        let got = Size::parse_const(input).unwrap();
+        assert_eq!(
+            got.value().to_bits(),
+            want.value().to_bits(),
+            "input: {:?}",
+            input
+        );
+    }
+
+    // Values that can differ in the last bits.
+    let inexact = &[
+        ("123456789012345678901 B", 123456789012345670000.0),
+        ("0.000000000000000000000001 B", 1.0000000000000001e-24),
+        ("9007199254740993 B", 9007199254740992.0),
+    ];
+    for (input, value) in inexact {
+        let want = input.parse::<Size>().unwrap();
+        let got = Size::parse_const(input).unwrap();
+        assert_eq!(got.value(), *value, "input: {:?}", input);
+        let ulps = got.value().to_bits().abs_diff(want.value().to_bits());
+        assert!(
+            ulps <= 2,
+            "input: {:?}, got: {}, want: {}",
+            input,
+            got,
+            want
+        );
+    }
+}
+
+#[test]
+fn from_conversions() {
+    let size = SpecificSize::new(1.5, Kibibyte).unwrap();
+    let bytes: SpecificSize<Byte> = SpecificSize::from(size);
+    assert_eq!(bytes.value(), 1536.0);
+    let bytes: SpecificSize<Byte> = From::from(SpecificSize::new(2, Quettabyte).unwrap());
+    assert_eq!(bytes.value(), 2e30);
+
+    let any = Size::from(size);
+    assert_eq!(any.multiple(), Any::Kibibyte);
+    assert_eq!(any.value(), 1.5);
+    assert_eq!(Size::from(bytes).multiple(), Any::Byte);
+
+    let size: Size = "10 GiB".parse().unwrap();
+    let mb = SpecificSize::<Megabyte>::from(size);
+    assert_eq!(mb.to_string(), "10737.41824 MB");
+    assert_eq!(SpecificSize::<Gigibyte>::from(size).value(), 10.0);
+    let mb: SpecificSize<Megabyte> = size.into();
+    assert_eq!(mb.to_string(), "10737.41824 MB");
+}
+
+macro_rules! same_conversion_test {
+    ($size:expr, $($name:ident),*) => {
+        let size: Size = $size;
+        $(
+            let from = SpecificSize::<$name>::from(size);
+            let into: SpecificSize<$name> = size.into();
+            assert_eq!(
+                from.value().to_bits(),
+                into.value().to_bits(),
+                "{} into {}",
+                size,
+                stringify!($name)
+            );
+
+            let from = SpecificSize::<Byte>::from(from);
+            let into: SpecificSize<Byte> = into.into();
+            assert_eq!(
+                from.value().to_bits(),
+                into.value().to_bits(),
+                "{} into {} into B",
+                size,
+                stringify!($name)
+            );
+        )*
+    };
+}
+
+#[test]
+fn from_and_into_are_the_same() {
+    let values = [
+        0.0,
+        1.0,
+        1.5,
+        10.0,
+        32.3,
+        100.0,
+        0.1,
+        1e-9,
+        123_456.789,
+        1e300,
+        f64::MAX,
+    ];
+    for value in values {
+        for multiple in Any::all() {
+            same_conversion_test!(
+                SpecificSize::new(value, *multiple).unwrap(),
+                Byte,
+                Kilobyte,
+                Megabyte,
+                Gigabyte,
+                Terabyte,
+                Petabyte,
+                Exabyte,
+                Zettabyte,
+                Yottabyte,
+                Ronnabyte,
+                Quettabyte,
+                Kibibyte,
+                Mebibyte,
+                Gigibyte,
+                Tebibyte,
+                Pebibyte,
+                Exbibyte,
+                Zebibyte,
+                Yobibyte,
+                Robibyte,
+                Quebibyte
+            );
+        }
+    }
+}
+
+#[test]
+fn try_from_integers() {
+    let size = SpecificSize::new(1.5, Kilobyte).unwrap();
+    assert_eq!(u64::try_from(size), Ok(1500));
+    assert_eq!(usize::try_from(size), Ok(1500));
+    assert_eq!(u128::try_from(size), Ok(1500));
+
+    // Rounding errors are ignored.
+    let size = SpecificSize::new(1.1, Kilobyte).unwrap();
+    assert_eq!(u64::try_from(size), Ok(1100));
+    let size = SpecificSize::new(0.3, Kilobyte).unwrap();
+    assert_eq!(u64::try_from(size), Ok(300));
+
+    let size = SpecificSize::new(0, Byte).unwrap();
+    assert_eq!(u64::try_from(size), Ok(0));
+    let size = SpecificSize::new(1.5, Byte).unwrap();
+    assert_eq!(u64::try_from(size), Err(TryFromSizeError::Fractional));
+    let size = SpecificSize::new(-1, Kilobyte).unwrap();
+    assert_eq!(u64::try_from(size), Err(TryFromSizeError::Negative));
+
+    let size = SpecificSize::new(16, Exbibyte).unwrap();
+    assert_eq!(u64::try_from(size), Err(TryFromSizeError::Overflow));
+    assert_eq!(u128::try_from(size), Ok(1 << 64));
+    let size = SpecificSize::new(15, Exbibyte).unwrap();
+    assert_eq!(u64::try_from(size), Ok(15 << 60));
+    let size = SpecificSize::new(f64::MAX, Quettabyte).unwrap();
+    assert_eq!(u128::try_from(size), Err(TryFromSizeError::Overflow));
+
+    assert_eq!(TryFromSizeError::Overflow.to_string(), "size too large");
+    assert_eq!(
+        TryFromSizeError::Fractional.to_string(),
+        "size is not a whole number of bytes"
+    );
+}
+
+#[test]
+fn conversion_chain() {
+    fn buffer_len(input: &str) -> Result<usize, Box<dyn std::error::Error>> {
+        let size: Size = input.parse()?;
+        Ok(SpecificSize::<Byte>::from(size).try_into()?)
+    }
+
+    assert_eq!(buffer_len("4 KiB").unwrap(), 4096);
+    assert_eq!(
+        buffer_len("4 invalid").unwrap_err().to_string(),
+        "invalid multiple"
+    );
+    assert_eq!(
+        buffer_len("0.5 B").unwrap_err().to_string(),
+        "size is not a whole number of bytes"
+    );
+}
+
+#[test]
+fn ord_size_transitivity() {
+    let a = SpecificSize::new(0, Byte).unwrap();
+    let b = SpecificSize::new(0.000_000_006, Byte).unwrap();
+    let c = SpecificSize::new(0.000_000_012, Byte).unwrap();
+    // `SpecificSize` equality isn't transitive.
+    assert_eq!(a, b);
+    assert_eq!(b, c);
+    assert_ne!(a, c);
+
+    // `OrdSize` is.
+    let (a, b, c) = (OrdSize::new(a), OrdSize::new(b), OrdSize::new(c));
+    assert!(a < b);
+    assert!(b < c);
+    assert!(a < c);
+
+    let mut sizes: Vec<OrdSize> = ["1 MiB", "1 B", "0 B", "1 MB", "1000 kB", "1024 KiB"]
+        .iter()
+        .map(|input| OrdSize::new(input.parse().unwrap()))
+        .collect();
+    sizes.push(OrdSize::new(SpecificSize::new(-1, Any::Kilobyte).unwrap()));
+    sizes.sort();
+    for window in sizes.windows(3) {
+        assert!(window[0] <= window[1]);
+        assert!(window[1] <= window[2]);
+        assert!(window[0] <= window[2]);
+    }
+    let sizes: Vec<String> = sizes.iter().map(|size| size.to_string()).collect();
+    assert_eq!(
+        sizes,
+        [
+            "-1 kB", "0 B", "1 B", "1 MB", "1000 kB", "1 MiB", "1024 KiB"
+        ]
+    );
+}
+
+#[test]
+fn ord_size_collections() {
+    use std::collections::{BTreeSet, HashSet};
+
+    let inputs = ["1 KiB", "1024 B", "1 kB", "0 B", "1 QiB"];
+    let mut sizes: Vec<OrdSize> = inputs
+        .iter()
+        .map(|input| OrdSize::new(input.parse().unwrap()))
+        .collect();
+    sizes.push(OrdSize::new(SpecificSize::new(-0.0, Any::Byte).unwrap()));
+    let hashed: HashSet<OrdSize> = sizes.iter().copied().collect();
+    let sorted: BTreeSet<OrdSize> = sizes.iter().copied().collect();
+    assert_eq!(hashed.len(), 4);
+    assert_eq!(sorted.len(), 4);
+    let bytes: Vec<f64> = sorted.iter().map(|size| size.bytes()).collect();
+    assert_eq!(bytes, [0.0, 1000.0, 1024.0, 2f64.powi(100)]);
+
+    let size = SpecificSize::new(2, Kilobyte).unwrap();
+    assert_eq!(OrdSize::from(size).size(), size);
+    assert_eq!(OrdSize::new(size), OrdSize::new(size));
+}
+
+#[test]
+fn min_max_clamp() {
+    let small = SpecificSize::new(1, Kilobyte).unwrap();
+    let large = SpecificSize::new(2, Kilobyte).unwrap();
+    assert_eq!(small.min(large), small);
+    assert_eq!(large.min(small), small);
+    assert_eq!(small.max(large), large);
+    assert_eq!(large.max(small), large);
+
+    let a: Size = "1024 B".parse().unwrap();
+    let b: Size = "1 KiB".parse().unwrap();
+    assert_eq!(a.min(b).multiple(), Any::Byte);
+    assert_eq!(a.max(b).multiple(), Any::Kibibyte);
+
+    let min: Size = "1 KiB".parse().unwrap();
+    let max: Size = "1 MiB".parse().unwrap();
+    for (input, want) in [
+        ("1 B", "1 KiB"),
+        ("1 KiB", "1 KiB"),
+        ("1 MB", "1 MB"),
+        ("1 MiB", "1 MiB"),
+        ("1 GB", "1 MiB"),
+    ] {
+        let size: Size = input.parse().unwrap();
+        assert_eq!(size.clamp(min, max).to_string(), want);
+    }
+}
+
+#[test]
+#[should_panic = "min size larger than max size"]
+fn clamp_invalid_range() {
+    let min = SpecificSize::new(2, Kilobyte).unwrap();
+    let max = SpecificSize::new(1, Kilobyte).unwrap();
+    let _ = min.clamp(min, max);
+}
+
+#[test]
+fn scale_aware_equality() {
+    let size = SpecificSize::new(32.3, Kilobyte).unwrap();
+    let converted: SpecificSize<Byte> = size.into();
+    assert_eq!(converted.value(), 32299.999999999996);
+    assert_eq!(size, converted);
+    assert_eq!(converted, size);
+
+    // Rounding errors at large scales.
+    let size = SpecificSize::new(1e30, Byte).unwrap();
+    assert_eq!(size, SpecificSize::new(1, Quettabyte).unwrap());
+    let size = SpecificSize::new(3.3, Yobibyte).unwrap();
+    let converted: SpecificSize<Kilobyte> = size.into();
+    assert_eq!(size, converted);
+    assert_eq!(converted.into::<Yobibyte>(), size);
+
+    // But not actual differences.
+    let size1 = SpecificSize::new(1, Yottabyte).unwrap();
+    let size2 = SpecificSize::new(1.000_000_001, Yottabyte).unwrap();
+    assert_ne!(size1, size2);
+    assert_ne!(size2, size1);
+    let size2 = SpecificSize::new(1e24 + 1e15, Byte).unwrap();
+    assert_ne!(size1, size2);
+    assert_ne!(size2, size1);
+}
+
+#[test]
+fn approx_eq() {
+    let size1 = SpecificSize::new(1, Yottabyte).unwrap();
+    let size2 = SpecificSize::new(1.000_001, Yottabyte).unwrap();
+    assert!(size1.approx_eq(size1, Tolerance::Absolute(0.0)));
+    assert!(size1.approx_eq(size1, Tolerance::Ulps(0)));
+    assert!(size1.approx_eq(size2, Tolerance::Relative(0.000_001_1)));
+    assert!(!size1.approx_eq(size2, Tolerance::Relative(0.000_000_9)));
+    assert!(size1.approx_eq(size2, Tolerance::Absolute(1e18)));
+    assert!(!size1.approx_eq(size2, Tolerance::Absolute(1e17)));
+
+    let size1 = SpecificSize::new(1, Byte).unwrap();
+    let size2 = SpecificSize::new(1.0 + f64::EPSILON, Byte).unwrap();
+    let size3 = SpecificSize::new(1.0 + 2.0 * f64::EPSILON, Byte).unwrap();
+    assert!(size1.approx_eq(size2, Tolerance::Ulps(1)));
+    assert!(!size1.approx_eq(size3, Tolerance::Ulps(1)));
+    assert!(size1.approx_eq(size3, Tolerance::Ulps(2)));
+    assert!(size3.approx_eq(size1, Tolerance::Ulps(2)));
+
+    // Different multiples.
+    let size1 = SpecificSize::new(1, Kibibyte).unwrap();
+    let size2 = SpecificSize::new(1.024, Kilobyte).unwrap();
+    assert!(size1.approx_eq(size2, Tolerance::Ulps(4)));
+
+    // Around zero.
+    let zero = SpecificSize::new(0.0, Byte).unwrap();
+    let negative_zero = SpecificSize::new(-0.0, Byte).unwrap();
+    assert!(zero.approx_eq(negative_zero, Tolerance::Ulps(0)));
+    let small = SpecificSize::new(f64::MIN_POSITIVE, Byte).unwrap();
+    let negative_small = SpecificSize::new(-f64::MIN_POSITIVE, Byte).unwrap();
+    assert!(!small.approx_eq(negative_small, Tolerance::Ulps(1)));
+    assert!(small.approx_eq(negative_small, Tolerance::Absolute(1.0)));
+}
+
+#[test]
+fn size_string() {
+    let size = SpecificSize::new(1.5, Mebibyte).unwrap();
+    let string = size.to_size_string();
+    assert_eq!(string, "1.5 MiB");
+    assert_eq!(string.as_str(), size.to_string());
+    assert_eq!(string.as_bytes(), b"1.5 MiB");
+    assert_eq!(format!("{:>9}", string), "  1.5 MiB");
+    assert_eq!(format!("{:?}", string), "\"1.5 MiB\"");
+    assert!(string.starts_with("1.5"));
+    assert_eq!(SizeString::new(), "");
+    assert_eq!(SizeString::default().len(), 0);
+
+    // Longest possible values fit for all multiples.
+    for value in [f64::MAX, -f64::MAX, f64::MIN_POSITIVE, -f64::MIN_POSITIVE] {
+        for multiple in Any::all() {
+            let size = SpecificSize::new(value, *multiple).unwrap();
+            let string = size.to_size_string();
+            assert_eq!(string, *size.to_string());
+        }
+    }
+    let size = SpecificSize::new(-f64::MIN_POSITIVE, Kibibyte).unwrap();
+    assert_eq!(size.to_size_string().len(), SizeString::CAPACITY);
+}
+
+#[test]
+fn format_into() {
+    use std::fmt::Write;
+
+    let size = SpecificSize::new(10, Kilobyte).unwrap();
+    let mut string = SizeString::new();
+    size.format_into(&mut string).unwrap();
+    string.write_str(", ").unwrap();
+    size.format_into(&mut string).unwrap();
+    assert_eq!(string, "10 kB, 10 kB");
+
+    let mut output = String::new();
+    size.format_into(&mut output).unwrap();
+    assert_eq!(output, "10 kB");
+
+    // Doesn't fit.
+    let custom = Custom::new("very long", "very long symbol", 1.0);
+    let size = SpecificSize::new(-f64::MIN_POSITIVE, Byte).unwrap();
+    let mut string = SizeString::new();
+    assert!(size.to_multiple(custom).format_into(&mut string).is_err());
+    let size = SpecificSize::new(1, Byte).unwrap();
+    let mut string = SizeString::new();
+    assert!(write!(string, "{:.400}", size).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn write_to() {
+    let size = SpecificSize::new(10, Kilobyte).unwrap();
+    let mut output = Vec::new();
+    size.write_to(&mut output).unwrap();
+    size.to_size().write_to(&mut output).unwrap();
+    assert_eq!(output, b"10 kB10 kB");
+}
+
+#[test]
+#[should_panic = "formatted size too long for SizeString"]
+fn size_string_too_long() {
+    let custom = Custom::new("very long", "very long symbol", 1.0);
+    let size = SpecificSize::new(-f64::MIN_POSITIVE, Byte).unwrap();
+    let _ = size.to_multiple(custom).to_size_string();
 }
@@ -0,0 +1,72 @@
+#![cfg(feature = "valuable")]
+
+use human_size::{Any, Byte, Kilobyte, Mebibyte, SpecificSize, Yobibyte};
+use valuable::{NamedValues, Valuable, Value, Visit};
+
+/// Collects the named fields of a structure as strings.
+#[derive(Default)]
+struct Fields(Vec<(String, String)>);
+
+impl Visit for Fields {
+    fn visit_value(&mut self, value: Value<'_>) {
+        if let Value::Structable(value) = value {
+            value.visit(self);
+        }
+    }
+
+    fn visit_named_fields(&mut self, named_values: &NamedValues<'_>) {
+        for (field, value) in named_values {
+            let value = match value {
+                Value::String(value) => value.to_string(),
+                Value::U64(value) => format!("U64({})", value),
+                Value::F64(value) => format!("F64({:?})", value),
+                value => panic!("unexpected value: {:?}", value),
+            };
+            self.0.push((field.name().to_owned(), value));
+        }
+    }
+}
+
+macro_rules! valuable_test {
+    ($value:expr, $multiple:expr, $human:expr, $bytes:expr) => {
+        let size = SpecificSize::new($value, $multiple).unwrap();
+        let mut fields = Fields::default();
+        valuable::visit(&size, &mut fields);
+        let want = vec![
+            ("human".to_owned(), $human.to_owned()),
+            ("bytes".to_owned(), $bytes.to_owned()),
+        ];
+        assert_eq!(fields.0, want, "input: {:?}", size);
+    };
+}
+
+#[test]
+fn structured_fields() {
+    valuable_test!(0, Byte, "0 B", "U64(0)");
+    valuable_test!(1.5, Kilobyte, "1.5 kB", "U64(1500)");
+    valuable_test!(1.5, Mebibyte, "1.5 MiB", "U64(1572864)");
+    valuable_test!(10, Any::Megabyte, "10 MB", "U64(10000000)");
+    // 32.3 kB is 32299.999999999996 bytes as `f64`.
+    valuable_test!(32.3, Kilobyte, "32.3 kB", "U64(32300)");
+    valuable_test!(4.1, Any::Megabyte, "4.1 MB", "U64(4100000)");
+
+    // Fractional, negative and too large values use `f64`.
+    valuable_test!(1.5, Byte, "1.5 B", "F64(1.5)");
+    valuable_test!(-1, Byte, "-1 B", "F64(-1.0)");
+    valuable_test!(1, Yobibyte, "1 YiB", "F64(1.2089258196146292e24)");
+}
+
+#[test]
+fn definition() {
+    let size = SpecificSize::new(1, Kilobyte).unwrap();
+    let Value::Structable(structable) = size.as_value() else {
+        panic!("expected structable");
+    };
+    let definition = structable.definition();
+    assert_eq!(definition.name(), "SpecificSize");
+    let valuable::Fields::Named(fields) = definition.fields() else {
+        panic!("expected named fields");
+    };
+    let names: Vec<&str> = fields.iter().map(|field| field.name()).collect();
+    assert_eq!(names, ["human", "bytes"]);
+}